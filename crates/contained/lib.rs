@@ -1,5 +1,72 @@
 //! `contained` is a collection of utilities and macros split into two focuses, wrapper types
 //! and getter / setter methods.
+//!
+//! ## Numeric wrappers
+//!
+//! The code generated by the `num_wrapper!` macro refers to the `num_traits` re-export of
+//! this crate, implementing its interfaces for a generic wrapper type by forwarding to the
+//! inner value:
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")] {
+//! use contained::num_traits::{Bounded, One, Zero};
+//! use contained::{binary_wrapper, num_wrapper};
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+//! pub struct Wrapper<T>(pub T);
+//!
+//! binary_wrapper! {
+//!     impl Wrapper {
+//!         Add.add,
+//!         Mul.mul,
+//!     }
+//! }
+//!
+//! num_wrapper! {
+//!     impl Wrapper {
+//!         Zero,
+//!         One,
+//!         Bounded,
+//!     }
+//! }
+//!
+//! assert!(Wrapper::<i32>::zero().is_zero());
+//! assert_eq!(Wrapper::<i32>::one(), Wrapper(1));
+//! assert_eq!(Wrapper::<u8>::max_value(), Wrapper(255));
+//! # }
+//! ```
+//!
+//! or, for transparent structs with a named field:
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")] {
+//! use contained::num_traits::{CheckedAdd, FromPrimitive, ToPrimitive};
+//! use contained::{binary_wrapper, num_wrapper};
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+//! pub struct Wrapper<T> {
+//!     pub field: T,
+//! }
+//!
+//! binary_wrapper! {
+//!     impl Wrapper.field {
+//!         Add.add,
+//!     }
+//! }
+//!
+//! num_wrapper! {
+//!     impl Wrapper.field {
+//!         FromPrimitive,
+//!         ToPrimitive,
+//!         CheckedAdd,
+//!     }
+//! }
+//!
+//! let x = Wrapper::<u8>::from_i64(200).unwrap();
+//! assert_eq!(x.to_f64(), Some(200.0));
+//! assert_eq!(x.checked_add(&x), None);
+//! # }
+//! ```
 #![crate_name = "contained"]
 #![crate_type = "lib"]
 #![allow(
@@ -0,0 +1,30 @@
+/*
+    appellation: atomic <test>
+    authors: @FL03
+*/
+use contained::Wrapper;
+use contained::wrappers::Atomic;
+use core::sync::atomic::Ordering;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Wrapper)]
+#[wrap(atomic)]
+pub struct UserId(u32);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Wrapper)]
+#[wrap(atomic)]
+pub struct Counter<T> {
+    pub count: T,
+}
+
+#[test]
+fn test_atomic_derived_wrapper() {
+    let id = Atomic::new(UserId::new(1));
+    assert_eq!(id.swap(UserId::new(2), Ordering::AcqRel), UserId::new(1));
+    assert_eq!(id.load(Ordering::Acquire).value(), 2);
+
+    let counter = Atomic::new(Counter::new(0_usize));
+    let _ = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |c| {
+        Some(c.map(|n| n + 1))
+    });
+    assert_eq!(counter.into_inner(), Counter::new(1));
+}
@@ -41,6 +41,7 @@ fmt_wrapper! {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_fmt_wrapper() {
     let a = A(42);
     let b = B { value: 42 };
@@ -0,0 +1,90 @@
+/*
+    appellation: from_str <test>
+    authors: @FL03
+*/
+use contained::{Error, ErrorKind, Wrapper};
+use std::net::Ipv4Addr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(from_str)]
+pub struct Port(u16);
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(from_str(suffix = "ms"))]
+pub struct Millis(u64);
+
+#[derive(Clone, Debug, PartialEq, Wrapper)]
+#[wrap(from_str(prefix = "#", suffix = ";"))]
+pub struct Tag {
+    name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(from_str)]
+pub struct Host<T>(T);
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(from_str(suffix = "%"), validate = "Percent::try_new")]
+pub struct Percent(u8);
+
+impl Percent {
+    pub fn try_new(value: u8) -> Result<Self, &'static str> {
+        if value <= 100 {
+            Ok(Self(value))
+        } else {
+            Err("the value exceeds 100")
+        }
+    }
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("8080".parse::<Port>().unwrap(), Port(8080));
+    assert_eq!(
+        "127.0.0.1".parse::<Host<Ipv4Addr>>().unwrap(),
+        Host(Ipv4Addr::LOCALHOST)
+    );
+    assert_eq!("0.5".parse::<Host<f32>>().unwrap(), Host(0.5));
+
+    let err = "http".parse::<Port>().unwrap_err();
+    assert!(matches!(err, Error::ParseIntError(_)));
+    let err = "localhost".parse::<Host<Ipv4Addr>>().unwrap_err();
+    assert!(matches!(err, Error::AddrParseError(_)));
+}
+
+#[test]
+fn test_affixes() {
+    assert_eq!("42ms".parse::<Millis>().unwrap(), Millis(42));
+    assert_eq!("#rust;".parse::<Tag>().unwrap(), Tag::new("rust".into()));
+
+    let err = "42s".parse::<Millis>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+    assert_eq!(
+        err.to_string(),
+        "unable to parse `Millis`: expected the suffix `ms`"
+    );
+    let err = "rust;".parse::<Tag>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unable to parse `Tag`: expected the prefix `#`"
+    );
+    assert!(matches!(
+        "4.2ms".parse::<Millis>(),
+        Err(Error::ParseIntError(_))
+    ));
+}
+
+#[test]
+fn test_validated() {
+    assert_eq!("75%".parse::<Percent>().unwrap(), Percent(75));
+    let err = "120%".parse::<Percent>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Validation);
+    assert_eq!(
+        err.to_string(),
+        "the value is not a valid `Percent`: the value exceeds 100"
+    );
+    assert_eq!(
+        "300%".parse::<Percent>().unwrap_err().kind(),
+        ErrorKind::Parse
+    );
+}
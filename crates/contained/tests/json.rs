@@ -0,0 +1,91 @@
+/*
+    appellation: json <test>
+    authors: @FL03
+*/
+use contained::Wrapper;
+use contained::json::{JsonExt, JsonSchema, json};
+use contained::wrappers::TotalOrd;
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(
+    serde = "transparent",
+    validate = "Self::try_new",
+    schema(minimum = -273)
+)]
+pub struct Celsius(i16);
+
+impl Celsius {
+    pub fn try_new(value: i16) -> Result<Self, &'static str> {
+        if value >= -273 {
+            Ok(Self(value))
+        } else {
+            Err("the value is below absolute zero")
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "struct", schema(min_length = 1, pattern = "^[a-z]+$"))]
+pub struct Username {
+    name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "tagged", schema)]
+pub struct Offset<T>(T);
+
+#[test]
+fn test_json_ext() -> contained::Result<()> {
+    let value = Celsius::try_new(-42).unwrap();
+    assert_eq!(value.to_json()?, "-42");
+    assert_eq!(value.to_json_value()?, json!(-42));
+    assert_eq!(Celsius::from_json("-42")?, value);
+    assert_eq!(Celsius::from_json_value(json!(7))?, Celsius(7));
+
+    let user = Username::new("alice".to_string());
+    assert_eq!(user.to_json()?, r#"{"name":"alice"}"#);
+    assert_eq!(Username::from_json(&user.to_json_pretty()?)?, user);
+    Ok(())
+}
+
+#[test]
+fn test_json_error() {
+    let err = Celsius::from_json("-300").unwrap_err();
+    assert!(matches!(err, contained::Error::Json(_)));
+    assert!(err.to_string().contains("the value is below absolute zero"));
+    assert!(matches!(
+        TotalOrd::<f64>::from_json("\"nope\""),
+        Err(contained::Error::Json(_))
+    ));
+}
+
+#[test]
+fn test_json_schema() {
+    assert_eq!(
+        Celsius::json_schema(),
+        json!({ "type": "integer", "minimum": -273, "maximum": 32767 })
+    );
+    assert_eq!(
+        Username::json_schema(),
+        json!({
+            "title": "Username",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 1, "pattern": "^[a-z]+$" },
+            },
+            "required": ["name"],
+            "additionalProperties": false,
+        })
+    );
+    assert_eq!(
+        Offset::<Option<f64>>::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "Offset": { "anyOf": [{ "type": "number" }, { "type": "null" }] },
+            },
+            "required": ["Offset"],
+            "additionalProperties": false,
+        })
+    );
+}
@@ -0,0 +1,110 @@
+/*
+    appellation: num <test>
+    authors: @FL03
+*/
+use contained::num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, Float, FromPrimitive, Num, NumCast, One, Pow,
+    SaturatingSub, Signed, ToPrimitive, WrappingMul, Zero,
+};
+use contained::{binary_wrapper, num_wrapper, unary_wrapper};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Meters<T>(pub T);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Count<T> {
+    pub value: T,
+}
+
+binary_wrapper! {
+    impl Meters {
+        Add.add,
+        Sub.sub,
+        Mul.mul,
+        Div.div,
+        Rem.rem,
+    }
+}
+
+unary_wrapper! {
+    impl Meters {
+        Neg.neg,
+    }
+}
+
+binary_wrapper! {
+    impl Count.value {
+        Add.add,
+        Sub.sub,
+        Mul.mul,
+        Div.div,
+        Rem.rem,
+    }
+}
+
+num_wrapper! {
+    impl Meters {
+        Zero,
+        One,
+        Num,
+        Bounded,
+        Signed,
+        Float,
+        FromPrimitive,
+        ToPrimitive,
+        NumCast,
+        Pow,
+    }
+}
+
+num_wrapper! {
+    impl Count.value {
+        Zero,
+        One,
+        Num,
+        Bounded,
+        FromPrimitive,
+        ToPrimitive,
+        CheckedAdd,
+        CheckedDiv,
+        SaturatingSub,
+        WrappingMul,
+    }
+}
+
+fn hypotenuse<T: Float>(a: T, b: T) -> T {
+    (a * a + b * b).sqrt()
+}
+
+fn sum<T: Num + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &v| acc + v)
+}
+
+#[test]
+fn test_num_wrapper_float() {
+    let c = hypotenuse(Meters(3.0_f64), Meters(4.0));
+    assert_eq!(c, Meters(5.0));
+    assert!(Meters::<f64>::nan().is_nan());
+    assert_eq!(Meters(-2.0_f64).abs(), Meters(2.0));
+    assert_eq!(Meters(2.0_f64).pow(2), Meters(4.0));
+    assert_eq!(<Meters<f32> as NumCast>::from(1.5_f64), Some(Meters(1.5)));
+    assert_eq!(Meters::<f64>::from_u8(2), Some(Meters(2.0)));
+    assert_eq!(Meters::<f64>::from_str_radix("1.5", 10).ok(), Some(Meters(1.5)));
+    assert!(Signed::is_negative(&Meters(-1.0_f64)));
+}
+
+#[test]
+fn test_num_wrapper_integer() {
+    let values = [Count { value: 1_u8 }, Count { value: 2 }, Count { value: 3 }];
+    assert_eq!(sum(&values), Count { value: 6 });
+    assert_eq!(Count::<u8>::max_value(), Count { value: u8::MAX });
+    assert!(Count::<u8>::zero().is_zero());
+    assert_eq!(Count::<u8>::one(), Count { value: 1 });
+    assert_eq!(Count { value: 250_u8 }.checked_add(&Count { value: 10 }), None);
+    assert_eq!(Count { value: 1_u8 }.checked_div(&Count { value: 0 }), None);
+    assert_eq!(Count { value: 1_u8 }.saturating_sub(&Count { value: 2 }), Count { value: 0 });
+    assert_eq!(Count { value: 128_u8 }.wrapping_mul(&Count { value: 2 }), Count { value: 0 });
+    assert_eq!(Count { value: 7_u8 }.to_f64(), Some(7.0));
+}
@@ -0,0 +1,122 @@
+/*
+    appellation: par <test>
+    authors: @FL03
+*/
+use contained::par::*;
+use contained::wrappers::TotalOrd;
+use contained::{Wrapper, binary_wrapper};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Wrapper)]
+#[repr(transparent)]
+#[wrap(par(sum, product))]
+pub struct Meters<T>(T);
+
+/// the folds are generated by `binary_wrapper!` rather than the derive
+#[derive(Clone, Copy, Debug, Default, PartialEq, Wrapper)]
+#[wrap(par)]
+pub struct Seconds<T>(T);
+
+binary_wrapper! {
+    impl Seconds {
+        Add.add,
+        Mul.mul,
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Wrapper)]
+#[wrap(par)]
+pub struct Bag {
+    items: Vec<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(par(sum), validate = "Small::try_new")]
+pub struct Small(u8);
+
+impl Small {
+    pub fn try_new(value: u8) -> Result<Self, &'static str> {
+        if value < 100 {
+            Ok(Self(value))
+        } else {
+            Err("the value is too large")
+        }
+    }
+}
+
+#[test]
+fn test_vec_ext() {
+    let wrapped: Vec<Meters<u32>> = (0..1_000).collect::<Vec<_>>().par_wrap();
+    assert_eq!(wrapped[10], Meters(10));
+    assert_eq!(wrapped.as_slice().par_map_inner(|x| x * 2)[10], 20);
+    assert_eq!(wrapped.as_slice().par_unwrap()[999], 999);
+    assert_eq!(wrapped.clone().par_map_inner(|x| x + 1)[0], 1);
+    assert_eq!(wrapped.par_unwrap(), (0..1_000).collect::<Vec<_>>());
+
+    let values = vec![1.0, 2.0];
+    let wrapped: Vec<TotalOrd<f64>> = values.as_slice().par_wrap();
+    assert_eq!(wrapped, vec![TotalOrd(1.0), TotalOrd(2.0)]);
+}
+
+#[test]
+fn test_transparent() {
+    let values = vec![1u64, 2, 3];
+    let ptr = values.as_ptr();
+    let mut wrapped: Vec<Meters<u64>> = values.wrap_in_place();
+    assert_eq!(wrapped.as_ptr().cast(), ptr);
+    wrapped.as_inner_slice_mut()[0] = 10;
+    assert_eq!(wrapped.as_inner_slice(), &[10, 2, 3]);
+    let values = wrapped.unwrap_in_place();
+    assert_eq!(values.as_ptr(), ptr);
+    assert_eq!(values, vec![10, 2, 3]);
+}
+
+#[test]
+fn test_collect_and_extend() {
+    let mut bag: Bag = (0..100u32).into_par_iter().collect();
+    assert_eq!(bag.get().len(), 100);
+    bag.par_extend(vec![100, 101]);
+    assert_eq!(bag.get().last(), Some(&101));
+
+    let set: Meters<BTreeSet<u8>> = vec![3, 1, 3, 2].into_par_iter().collect();
+    assert_eq!(set.value().into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_sum_and_product() {
+    let values: Vec<Meters<u64>> = (1..=100).map(Meters).collect();
+    assert_eq!(
+        values.par_iter().copied().sum::<Meters<u64>>(),
+        Meters(5050)
+    );
+    assert_eq!(values.iter().sum::<Meters<u64>>(), Meters(5050));
+    assert_eq!(
+        values[..5].par_iter().copied().product::<Meters<u64>>(),
+        Meters(120)
+    );
+    assert_eq!(values[..5].iter().product::<Meters<u64>>(), Meters(120));
+}
+
+#[test]
+fn test_with_binary_wrapper() {
+    let values: Vec<Seconds<u64>> = (1..=4).collect::<Vec<_>>().par_wrap();
+    assert_eq!(values[0] + values[1], Seconds(3));
+    assert_eq!(
+        values.par_iter().copied().sum::<Seconds<u64>>(),
+        Seconds(10)
+    );
+    assert_eq!(values.iter().product::<Seconds<u64>>(), Seconds(24));
+}
+
+#[test]
+fn test_validated() {
+    let values = vec![Small(40), Small(50)];
+    assert_eq!(values.par_iter().copied().sum::<Small>(), Small(90));
+}
+
+#[test]
+#[should_panic(expected = "the value is not a valid `Small`")]
+fn test_validated_overflow() {
+    let values = [Small(60), Small(50)];
+    let _ = values.iter().sum::<Small>();
+}
@@ -0,0 +1,83 @@
+/*
+    appellation: rand <test>
+    authors: @FL03
+*/
+use contained::Wrapper;
+use contained::rand::{Distribution, SeedableRng, StandardUniform, Uniform};
+use contained::wrappers::{NotNan, TotalOrd};
+
+type Rng = rand::rngs::SmallRng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(rand)]
+pub struct Sample<T>(T);
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(rand(range = 1..=6))]
+pub struct Die(u8);
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(rand, validate = "Even::try_new")]
+pub struct Even {
+    value: u32,
+}
+
+impl Even {
+    pub fn try_new(value: u32) -> Result<Self, &'static str> {
+        if value.is_multiple_of(2) {
+            Ok(Self { value })
+        } else {
+            Err("the value is odd")
+        }
+    }
+}
+
+#[test]
+fn test_distribution() {
+    let mut rng = Rng::seed_from_u64(42);
+    let a: Sample<u64> = StandardUniform.sample(&mut rng);
+    let b = Sample::<u64>::random_with(&mut Rng::seed_from_u64(42));
+    assert_eq!(a, Sample::new(b.value()));
+    // the standard distribution samples floats from the half-open interval [0, 1)
+    for _ in 0..100 {
+        let x = Sample::<f64>::random();
+        assert!((0.0..1.0).contains(x.get()));
+    }
+}
+
+#[test]
+fn test_bounded() {
+    let mut rng = Rng::seed_from_u64(7);
+    for _ in 0..1_000 {
+        assert!((1..=6).contains(Die::random_with(&mut rng).get()));
+    }
+    for _ in 0..100 {
+        let value = Die::random_range(10..20);
+        assert!((10..20).contains(value.get()));
+    }
+}
+
+#[test]
+fn test_validated() {
+    for _ in 0..100 {
+        assert!(Even::random().get().is_multiple_of(2));
+        assert!(Even::random_range(1..=3).get() == &2);
+        let value = Even::sample_from(Uniform::new(0, 1_000).unwrap());
+        assert!(value.get().is_multiple_of(2) && *value.get() < 1_000);
+    }
+}
+
+#[test]
+#[should_panic(expected = "failed to sample a valid value")]
+fn test_unsatisfiable() {
+    Even::random_range(1..2);
+}
+
+#[test]
+fn test_float_wrappers() {
+    let mut rng = Rng::seed_from_u64(3);
+    let a: TotalOrd<f32> = StandardUniform.sample(&mut rng);
+    let b: NotNan<f64> = StandardUniform.sample(&mut rng);
+    assert!((0.0..1.0).contains(&a.0));
+    assert!((0.0..1.0).contains(b.get()));
+}
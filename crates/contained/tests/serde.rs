@@ -0,0 +1,126 @@
+/*
+    appellation: serde <test>
+    authors: @FL03
+*/
+use contained::Wrapper;
+use contained::wrappers::TotalOrd;
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "transparent")]
+pub struct Transparent<T>(T);
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "tagged")]
+pub struct Tagged<T>(T);
+
+#[derive(Clone, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "struct")]
+pub struct Named {
+    name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "transparent", validate = "Even::try_new")]
+pub struct Even(u32);
+
+impl Even {
+    pub fn try_new(value: u32) -> Result<Self, &'static str> {
+        if value.is_multiple_of(2) {
+            Ok(Self(value))
+        } else {
+            Err("the value is odd")
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(serde = "struct", validate = "Self::try_new")]
+pub struct Port(u16);
+
+impl Port {
+    pub fn try_new(value: u16) -> Result<Self, &'static str> {
+        if value != 0 {
+            Ok(Self(value))
+        } else {
+            Err("the port is zero")
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Record {
+    #[serde(with = "contained::serde::transparent")]
+    score: TotalOrd<f64>,
+    #[serde(with = "contained::serde::tagged")]
+    rank: TotalOrd<f32>,
+}
+
+contained::wrapper! {
+    #[derive(Debug)]
+    Tally(pub T);,
+}
+
+#[test]
+fn test_transparent() {
+    let value = Transparent::new(42u8);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "42");
+    assert_eq!(
+        serde_json::from_str::<Transparent<u8>>(&json).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn test_tagged() {
+    let value = Tagged::new(1.5f64);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"Tagged":1.5}"#);
+    assert_eq!(serde_json::from_str::<Tagged<f64>>(&json).unwrap(), value);
+    // the tag must match the name of the wrapper
+    assert!(serde_json::from_str::<Tagged<f64>>(r#"{"Other":1.5}"#).is_err());
+}
+
+#[test]
+fn test_struct() {
+    let value = Named::new("alice".to_string());
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"name":"alice"}"#);
+    assert_eq!(serde_json::from_str::<Named>(&json).unwrap(), value);
+    // unnamed fields are represented using the default field name
+    let json = serde_json::to_string(&Port::try_new(8080).unwrap()).unwrap();
+    assert_eq!(json, r#"{"value":8080}"#);
+    assert!(serde_json::from_str::<Named>(r#"{"other":"alice"}"#).is_err());
+    assert!(serde_json::from_str::<Named>("{}").is_err());
+}
+
+#[test]
+fn test_validated() {
+    assert_eq!(serde_json::from_str::<Even>("4").unwrap(), Even(4));
+    let err = serde_json::from_str::<Even>("5").unwrap_err();
+    assert!(err.to_string().contains("the value is odd"));
+    assert_eq!(
+        serde_json::from_str::<Port>(r#"{"value":443}"#).unwrap(),
+        Port(443)
+    );
+    assert!(serde_json::from_str::<Port>(r#"{"value":0}"#).is_err());
+}
+
+#[test]
+fn test_with_helpers() {
+    let record = Record {
+        score: TotalOrd(0.5),
+        rank: TotalOrd(2.0),
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"score":0.5,"rank":{"TotalOrd":2.0}}"#);
+    assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+}
+
+#[test]
+fn test_wrapper_macro() {
+    let tally = Tally::new(3);
+    let json = serde_json::to_string(&tally).unwrap();
+    assert_eq!(json, "3");
+    assert_eq!(serde_json::from_str::<Tally<u64>>(&json).unwrap(), tally);
+}
@@ -0,0 +1,63 @@
+/*
+    appellation: wasm <test>
+    authors: @FL03
+*/
+use contained::Wrapper;
+use contained::wasm::*;
+
+/// the field lacks a reserved `None` value, leaving the optional conversions unavailable
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(wasm)]
+pub struct UserId(u32);
+
+#[derive(Clone, Debug, PartialEq, Wrapper)]
+#[wrap(wasm(option))]
+pub struct Label {
+    text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Wrapper)]
+#[wrap(wasm(option))]
+pub struct Tagged<T>(T);
+
+/// ensures the type may be passed to and from JS, optionally
+fn assert_optional_abi<T: OptionIntoWasmAbi + OptionFromWasmAbi>() {}
+
+/// passes the value across the ABI and back
+fn roundtrip<T>(value: T) -> T
+where
+    T: IntoWasmAbi + FromWasmAbi<Abi = <T as IntoWasmAbi>::Abi>,
+{
+    let abi = value.into_abi();
+    unsafe { T::from_abi(abi) }
+}
+
+#[test]
+fn test_wasm_abi_is_inner() {
+    // the wrapper is passed as a plain JS number
+    let abi: u32 = UserId(42).into_abi();
+    assert_eq!(abi, 42);
+    assert_eq!(unsafe { UserId::from_abi(7) }, UserId(7));
+    assert_eq!(roundtrip(UserId(u32::MAX)), UserId(u32::MAX));
+    assert_eq!(roundtrip(Tagged(-1.5_f64)), Tagged(-1.5));
+}
+
+#[test]
+fn test_wasm_abi_optional() {
+    // allocated values are passed as pointers into the wasm memory, so only their types are
+    // checked on the host
+    assert_optional_abi::<Label>();
+    assert_optional_abi::<Tagged<u16>>();
+    // fields reserving a `None` value share it with the wrapper
+    assert_eq!(Tagged::<u16>::none(), <u16 as OptionIntoWasmAbi>::none());
+    assert!(Tagged::<u16>::is_none(&Tagged::<u16>::none()));
+    assert!(!Tagged::<u16>::is_none(&Tagged(3_u16).into_abi()));
+
+    let abi = Some(Tagged(3_u8)).into_abi();
+    assert_eq!(
+        unsafe { Option::<Tagged<u8>>::from_abi(abi) },
+        Some(Tagged(3))
+    );
+    let abi = None::<Tagged<u8>>.into_abi();
+    assert_eq!(unsafe { Option::<Tagged<u8>>::from_abi(abi) }, None);
+}
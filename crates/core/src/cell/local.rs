@@ -0,0 +1,112 @@
+/*
+    Appellation: local <module>
+    Created At: 2026.10.19:12:40:26
+    Contrib: @FL03
+*/
+use crate::Result;
+use crate::traits::InteriorMut;
+use core::cell::Cell;
+
+/// The [`Local`] wrapper provides single-threaded interior mutability for [`Copy`] values
+/// using a [`Cell`]; since the value is always copied in and out, access never fails.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct Local<T>(Cell<T>);
+
+impl<T> Local<T> {
+    /// returns a new instance with the given value
+    pub const fn new(value: T) -> Self {
+        Self(Cell::new(value))
+    }
+    /// returns a reference to the underlying [`Cell`]
+    pub const fn as_cell(&self) -> &Cell<T> {
+        &self.0
+    }
+    /// returns a copy of the inner value
+    pub fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        self.0.get()
+    }
+    /// returns a mutable reference to the inner value
+    pub const fn get_mut(&mut self) -> &mut T {
+        self.0.get_mut()
+    }
+    /// consumes the current instance to return the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+impl<T> InteriorMut<T> for Local<T>
+where
+    T: Copy,
+{
+    type Cont<U> = Local<U>;
+
+    fn with<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        Ok(f(&self.0.get()))
+    }
+
+    fn with_mut<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut value = self.0.get();
+        let res = f(&mut value);
+        self.0.set(value);
+        Ok(res)
+    }
+
+    fn map<U, F>(&self, f: F) -> Result<Self::Cont<U>>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        Ok(Local::new(f(&self.0.get())))
+    }
+
+    fn replace(&self, value: T) -> Result<T> {
+        Ok(self.0.replace(value))
+    }
+
+    fn set(&self, value: T) -> Result<()> {
+        self.0.set(value);
+        Ok(())
+    }
+
+    fn take(&self) -> Result<T>
+    where
+        T: Default,
+    {
+        Ok(self.0.take())
+    }
+}
+
+impl<T> Clone for Local<T>
+where
+    T: Copy,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
+impl<T> core::fmt::Debug for Local<T>
+where
+    T: Copy + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Local").field(&self.get()).finish()
+    }
+}
+
+impl<T> From<T> for Local<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,90 @@
+/*
+    Appellation: shared <module>
+    Created At: 2026.10.19:12:52:44
+    Contrib: @FL03
+*/
+use crate::Result;
+use crate::traits::InteriorMut;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+/// The [`Shared`] wrapper provides single-threaded shared ownership with interior mutability,
+/// backed by an [`Rc`] of a [`RefCell`]. Cloning the wrapper yields another handle to the
+/// same value, and conflicting borrows are reported as errors rather than panicking.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct Shared<T>(Rc<RefCell<T>>);
+
+impl<T> Shared<T> {
+    /// returns a new instance with the given value
+    pub fn new(value: T) -> Self {
+        Self(Rc::new(RefCell::new(value)))
+    }
+    /// returns a reference to the underlying [`Rc`]
+    pub const fn as_rc(&self) -> &Rc<RefCell<T>> {
+        &self.0
+    }
+    /// returns true if both handles point to the same value
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+    /// returns the number of handles pointing to the inner value
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(&self.0)
+    }
+    /// attempts to return the inner value, succeeding only when this is the sole handle
+    #[inline]
+    pub fn try_into_inner(self) -> core::result::Result<T, Self> {
+        Rc::try_unwrap(self.0)
+            .map(RefCell::into_inner)
+            .map_err(Self)
+    }
+}
+
+impl<T> InteriorMut<T> for Shared<T> {
+    type Cont<U> = Shared<U>;
+
+    fn with<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let guard = self.0.try_borrow()?;
+        Ok(f(&guard))
+    }
+
+    fn with_mut<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = self.0.try_borrow_mut()?;
+        Ok(f(&mut guard))
+    }
+
+    fn map<U, F>(&self, f: F) -> Result<Self::Cont<U>>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        self.with(f).map(Shared::new)
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T> core::fmt::Debug for Shared<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Shared").field(&self.0).finish()
+    }
+}
+
+impl<T> From<T> for Shared<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,94 @@
+/*
+    Appellation: sync <module>
+    Created At: 2026.10.19:13:05:12
+    Contrib: @FL03
+*/
+use crate::Result;
+use crate::traits::InteriorMut;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// The [`Sync`] wrapper provides thread-safe shared ownership with interior mutability, backed
+/// by an [`Arc`] of a [`RwLock`]. Cloning the wrapper yields another handle to the same value,
+/// and a poisoned lock is reported as [`Error::Poisoned`](crate::Error::Poisoned).
+#[derive(Default)]
+#[repr(transparent)]
+pub struct Sync<T>(Arc<RwLock<T>>);
+
+impl<T> Sync<T> {
+    /// returns a new instance with the given value
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(RwLock::new(value)))
+    }
+    /// returns a reference to the underlying [`Arc`]
+    pub const fn as_arc(&self) -> &Arc<RwLock<T>> {
+        &self.0
+    }
+    /// returns true if both handles point to the same value
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+    /// returns the number of handles pointing to the inner value
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+    /// returns true if the underlying lock has been poisoned
+    pub fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+    /// attempts to return the inner value, succeeding only when this is the sole handle; the
+    /// value is returned even if the lock was poisoned
+    #[inline]
+    pub fn try_into_inner(self) -> core::result::Result<T, Self> {
+        Arc::try_unwrap(self.0)
+            .map(|lock| lock.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .map_err(Self)
+    }
+}
+
+impl<T> InteriorMut<T> for Sync<T> {
+    type Cont<U> = Sync<U>;
+
+    fn with<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let guard = self.0.read()?;
+        Ok(f(&guard))
+    }
+
+    fn with_mut<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = self.0.write()?;
+        Ok(f(&mut guard))
+    }
+
+    fn map<U, F>(&self, f: F) -> Result<Self::Cont<U>>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        self.with(f).map(Sync::new)
+    }
+}
+
+impl<T> Clone for Sync<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> core::fmt::Debug for Sync<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Sync").field(&self.0).finish()
+    }
+}
+
+impl<T> From<T> for Sync<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,216 @@
+/*
+    Appellation: entry <module>
+    Created At: 2026.10.19:19:04:12
+    Contrib: @FL03
+*/
+use super::key::Key;
+use crate::traits::Newtype;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::hash_map::{RawOccupiedEntryMut, RawVacantEntryMut};
+
+/// A view into a single entry of a [`WrapperMap`](super::WrapperMap), which may either be
+/// vacant or occupied
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+/// A view into a single entry of a [`WrapperMap`](super::WrapperMap) located using a borrowed
+/// query, which is only converted into a key when inserting into a vacant entry
+pub enum EntryRef<'a, 'b, K, Q: ?Sized, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, S>),
+}
+
+/// A view into an occupied entry of a [`WrapperMap`](super::WrapperMap)
+pub struct OccupiedEntry<'a, K, V, S> {
+    pub(crate) raw: RawOccupiedEntryMut<'a, Key<K>, V, S>,
+}
+
+/// A view into a vacant entry of a [`WrapperMap`](super::WrapperMap), owning its key
+pub struct VacantEntry<'a, K, V, S> {
+    pub(crate) raw: RawVacantEntryMut<'a, Key<K>, V, S>,
+    pub(crate) key: K,
+}
+
+/// A view into a vacant entry of a [`WrapperMap`](super::WrapperMap), borrowing its query
+pub struct VacantEntryRef<'a, 'b, K, Q: ?Sized, V, S> {
+    pub(crate) raw: RawVacantEntryMut<'a, Key<K>, V, S>,
+    pub(crate) key: &'b Q,
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: Newtype,
+    K::Inner: Hash,
+    S: BuildHasher,
+{
+    /// returns a reference to the key of the entry
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+    /// ensures the entry holds a value by inserting the default if vacant, returning a mutable
+    /// reference to the value
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+    /// ensures the entry holds a value by inserting the result of the function if vacant,
+    /// returning a mutable reference to the value
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+    /// ensures the entry holds a value by inserting the result of the function, given the
+    /// key, if vacant
+    pub fn or_insert_with_key<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce(&K) -> V,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+    /// ensures the entry holds a value by inserting the default value if vacant
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+    /// applies the function to the value of an occupied entry
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> EntryRef<'a, 'b, K, Q, V, S>
+where
+    K: Newtype,
+    K::Inner: Hash + From<&'b Q>,
+    Q: ?Sized,
+    S: BuildHasher,
+{
+    /// ensures the entry holds a value by inserting the default if vacant, returning a mutable
+    /// reference to the value
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+    /// ensures the entry holds a value by inserting the result of the function if vacant,
+    /// returning a mutable reference to the value
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+    /// ensures the entry holds a value by inserting the default value if vacant
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+    /// applies the function to the value of an occupied entry
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    /// returns a reference to the key of the entry
+    pub fn key(&self) -> &K {
+        &self.raw.key().0
+    }
+    /// returns a reference to the value of the entry
+    pub fn get(&self) -> &V {
+        self.raw.get()
+    }
+    /// returns a mutable reference to the value of the entry
+    pub fn get_mut(&mut self) -> &mut V {
+        self.raw.get_mut()
+    }
+    /// converts the entry into a mutable reference to its value, bound to the map
+    pub fn into_mut(self) -> &'a mut V {
+        self.raw.into_mut()
+    }
+    /// replaces the value of the entry, returning the previous value
+    pub fn insert(&mut self, value: V) -> V {
+        self.raw.insert(value)
+    }
+    /// removes the entry from the map, returning its value
+    pub fn remove(self) -> V {
+        self.raw.remove()
+    }
+    /// removes the entry from the map, returning its key and value
+    pub fn remove_entry(self) -> (K, V) {
+        let (Key(key), value) = self.raw.remove_entry();
+        (key, value)
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S> {
+    /// returns a reference to the key of the entry
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+    /// consumes the entry to return its key
+    pub fn into_key(self) -> K {
+        self.key
+    }
+    /// inserts the value into the map using the key of the entry
+    pub fn insert(self, value: V) -> &'a mut V
+    where
+        K: Newtype,
+        K::Inner: Hash,
+        S: BuildHasher,
+    {
+        self.raw.insert(Key(self.key), value).1
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> VacantEntryRef<'a, 'b, K, Q, V, S>
+where
+    Q: ?Sized,
+{
+    /// returns a reference to the query of the entry
+    pub fn key(&self) -> &'b Q {
+        self.key
+    }
+    /// inserts the value into the map, converting the query into a key
+    pub fn insert(self, value: V) -> &'a mut V
+    where
+        K: Newtype,
+        K::Inner: Hash + From<&'b Q>,
+        S: BuildHasher,
+    {
+        let key = K::from_inner(self.key.into());
+        self.raw.insert(Key(key), value).1
+    }
+}
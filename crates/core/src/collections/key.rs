@@ -0,0 +1,63 @@
+/*
+    Appellation: key <module>
+    Created At: 2026.10.19:18:58:31
+    Contrib: @FL03
+*/
+use crate::traits::Newtype;
+use core::hash::{Hash, Hasher};
+use hashbrown::Equivalent;
+
+/// [`Key`] stores a wrapper within a map, hashing and comparing it using its inner value so
+/// lookups by the inner value remain consistent regardless of the wrapper's own implementations
+#[derive(Clone)]
+#[repr(transparent)]
+pub(crate) struct Key<W>(pub W);
+
+/// [`Query`] adapts a borrowed query into one comparable with the stored [`Key`]s
+pub(crate) struct Query<'a, Q: ?Sized>(pub &'a Q);
+
+impl<W> Hash for Key<W>
+where
+    W: Newtype,
+    W::Inner: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_inner().hash(state)
+    }
+}
+
+impl<W> PartialEq for Key<W>
+where
+    W: Newtype,
+    W::Inner: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_inner() == other.0.as_inner()
+    }
+}
+
+impl<W> Eq for Key<W>
+where
+    W: Newtype,
+    W::Inner: Eq,
+{
+}
+
+impl<Q> Hash for Query<'_, Q>
+where
+    Q: Hash + ?Sized,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<W, Q> Equivalent<Key<W>> for Query<'_, Q>
+where
+    W: Newtype,
+    Q: Equivalent<W::Inner> + ?Sized,
+{
+    fn equivalent(&self, key: &Key<W>) -> bool {
+        self.0.equivalent(key.0.as_inner())
+    }
+}
@@ -0,0 +1,418 @@
+/*
+    Appellation: map <module>
+    Created At: 2026.10.19:19:15:47
+    Contrib: @FL03
+*/
+use super::entry::{Entry, EntryRef, OccupiedEntry, VacantEntry, VacantEntryRef};
+use super::key::{Key, Query};
+use crate::traits::Newtype;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::hash_map::{self, HashMap, RawEntryMut};
+use hashbrown::{DefaultHashBuilder, Equivalent};
+
+/// The [`WrapperMap`] is a hash map keyed by wrappers, built on [`hashbrown`], which may be
+/// queried using any value [`Equivalent`] to the inner value of its keys; i.e. a map keyed by
+/// `W<String>` may be queried using a `&str` without constructing a wrapper.
+///
+/// Keys are hashed and compared using their inner values, ignoring any implementations of
+/// [`Hash`] and [`Eq`] provided by the wrapper itself.
+///
+/// ```rust
+/// use contained_core::collections::WrapperMap;
+/// use contained_core::wrappers::TotalOrd;
+///
+/// let mut map = WrapperMap::new();
+/// map.insert(TotalOrd(String::from("a")), 1);
+/// assert_eq!(map.get("a"), Some(&1));
+/// *map.entry_ref("b").or_insert(2) += 1;
+/// assert_eq!(map["b"], 3);
+/// ```
+pub struct WrapperMap<K, V, S = DefaultHashBuilder> {
+    pub(crate) map: HashMap<Key<K>, V, S>,
+}
+
+/// An iterator over the entries of a [`WrapperMap`]
+pub struct Iter<'a, K, V> {
+    iter: hash_map::Iter<'a, Key<K>, V>,
+}
+
+/// A mutable iterator over the entries of a [`WrapperMap`]
+pub struct IterMut<'a, K, V> {
+    iter: hash_map::IterMut<'a, Key<K>, V>,
+}
+
+/// An owning iterator over the entries of a [`WrapperMap`]
+pub struct IntoIter<K, V> {
+    iter: hash_map::IntoIter<Key<K>, V>,
+}
+
+impl<K, V> WrapperMap<K, V> {
+    /// returns a new, empty map
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultHashBuilder::default())
+    }
+    /// returns a new, empty map with at least the given capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, DefaultHashBuilder::default())
+    }
+}
+
+impl<K, V, S> WrapperMap<K, V, S> {
+    /// returns a new, empty map using the given hash builder
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hasher),
+        }
+    }
+    /// returns a new, empty map with at least the given capacity using the given hash builder
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hasher),
+        }
+    }
+    /// returns a reference to the hash builder of the map
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+    /// returns the number of elements the map can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+    /// returns the number of entries within the map
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    /// returns true if the map contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    /// removes every entry from the map
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+    /// returns an iterator over the entries of the map
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.map.iter(),
+        }
+    }
+    /// returns an iterator over the entries of the map, with mutable references to the values
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.map.iter_mut(),
+        }
+    }
+    /// returns an iterator over the keys of the map
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.map.keys().map(|Key(key)| key)
+    }
+    /// returns an iterator over the values of the map
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.map.values()
+    }
+    /// returns an iterator over mutable references to the values of the map
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.map.values_mut()
+    }
+    /// retains only the entries for which the predicate returns true
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.map.retain(|Key(key), value| f(key, value))
+    }
+}
+
+impl<K, V, S> WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+    /// reserves capacity for at least the given number of additional entries
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+    /// inserts the entry into the map, returning the previous value of the key, if any; the
+    /// existing key is kept
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+    /// returns true if the map contains an entry for the given query
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+    /// returns a reference to the value of the given query
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.get(&Query(key))
+    }
+    /// returns references to the key and value of the given query
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map
+            .get_key_value(&Query(key))
+            .map(|(Key(key), value)| (key, value))
+    }
+    /// returns a mutable reference to the value of the given query
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.get_mut(&Query(key))
+    }
+    /// removes the entry of the given query, returning its value
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.remove(&Query(key))
+    }
+    /// removes the entry of the given query, returning its key and value
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map
+            .remove_entry(&Query(key))
+            .map(|(Key(key), value)| (key, value))
+    }
+    /// returns the entry of the given key for in-place manipulation
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        match self.map.raw_entry_mut().from_key(&Query(key.as_inner())) {
+            RawEntryMut::Occupied(raw) => Entry::Occupied(OccupiedEntry { raw }),
+            RawEntryMut::Vacant(raw) => Entry::Vacant(VacantEntry { raw, key }),
+        }
+    }
+    /// returns the entry of the given query for in-place manipulation, only creating a key
+    /// from the query when inserting into a vacant entry
+    pub fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, S>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        match self.map.raw_entry_mut().from_key(&Query(key)) {
+            RawEntryMut::Occupied(raw) => EntryRef::Occupied(OccupiedEntry { raw }),
+            RawEntryMut::Vacant(raw) => EntryRef::Vacant(VacantEntryRef { raw, key }),
+        }
+    }
+    /// returns the hash of the given query, as computed by the map
+    pub fn hash_one<Q>(&self, key: &Q) -> u64
+    where
+        Q: Hash + ?Sized,
+    {
+        self.map.hasher().hash_one(key)
+    }
+    /// returns the entry with the given hash satisfying the predicate, if any; the hash must
+    /// be computed by [`hash_one`](Self::hash_one) using a value equivalent to the key
+    pub fn find_by_hash<F>(&self, hash: u64, mut is_match: F) -> Option<(&K, &V)>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        self.map
+            .raw_entry()
+            .from_hash(hash, |Key(key)| is_match(key))
+            .map(|(Key(key), value)| (key, value))
+    }
+    /// returns the entry with the given hash satisfying the predicate, if any, for in-place
+    /// manipulation
+    pub fn find_by_hash_mut<F>(
+        &mut self,
+        hash: u64,
+        mut is_match: F,
+    ) -> Option<OccupiedEntry<'_, K, V, S>>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        match self
+            .map
+            .raw_entry_mut()
+            .from_hash(hash, |Key(key)| is_match(key))
+        {
+            RawEntryMut::Occupied(raw) => Some(OccupiedEntry { raw }),
+            RawEntryMut::Vacant(_) => None,
+        }
+    }
+}
+
+impl<K, V, S> Clone for WrapperMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K, V, S> Default for WrapperMap<K, V, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> core::fmt::Debug for WrapperMap<K, V, S>
+where
+    K: core::fmt::Debug,
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> PartialEq for WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K, V, S> Eq for WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, Q, V, S> core::ops::Index<&Q> for WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    Q: Hash + Equivalent<K::Inner> + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        iter.into_iter().for_each(|(key, value)| {
+            self.insert(key, value);
+        })
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for WrapperMap<K, V, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> IntoIterator for WrapperMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a WrapperMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut WrapperMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(Key(key), value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(Key(key), value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(Key(key), value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
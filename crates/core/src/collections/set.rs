@@ -0,0 +1,305 @@
+/*
+    Appellation: set <module>
+    Created At: 2026.10.19:19:31:09
+    Contrib: @FL03
+*/
+use super::entry::EntryRef;
+use super::map::{self, WrapperMap};
+use crate::traits::Newtype;
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{DefaultHashBuilder, Equivalent};
+
+/// The [`WrapperSet`] is a hash set of wrappers, built on [`hashbrown`], which may be queried
+/// using any value [`Equivalent`] to the inner value of its elements.
+///
+/// Like the [`WrapperMap`], elements are hashed and compared using their inner values.
+///
+/// ```rust
+/// use contained_core::collections::WrapperSet;
+/// use contained_core::wrappers::TotalOrd;
+///
+/// let mut set = WrapperSet::new();
+/// assert!(set.insert(TotalOrd(String::from("a"))));
+/// assert!(!set.insert(TotalOrd(String::from("a"))));
+/// assert!(set.contains("a"));
+/// assert_eq!(set.take("a").map(|TotalOrd(s)| s), Some(String::from("a")));
+/// ```
+pub struct WrapperSet<K, S = DefaultHashBuilder> {
+    map: WrapperMap<K, (), S>,
+}
+
+/// An iterator over the elements of a [`WrapperSet`]
+pub struct SetIter<'a, K> {
+    iter: map::Iter<'a, K, ()>,
+}
+
+/// An owning iterator over the elements of a [`WrapperSet`]
+pub struct SetIntoIter<K> {
+    iter: map::IntoIter<K, ()>,
+}
+
+impl<K> WrapperSet<K> {
+    /// returns a new, empty set
+    pub fn new() -> Self {
+        Self {
+            map: WrapperMap::new(),
+        }
+    }
+    /// returns a new, empty set with at least the given capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: WrapperMap::with_capacity(capacity),
+        }
+    }
+}
+
+impl<K, S> WrapperSet<K, S> {
+    /// returns a new, empty set using the given hash builder
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: WrapperMap::with_hasher(hasher),
+        }
+    }
+    /// returns a new, empty set with at least the given capacity using the given hash builder
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self {
+            map: WrapperMap::with_capacity_and_hasher(capacity, hasher),
+        }
+    }
+    /// returns a reference to the hash builder of the set
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+    /// returns the number of elements within the set
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    /// returns true if the set contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    /// removes every element from the set
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+    /// returns an iterator over the elements of the set
+    pub fn iter(&self) -> SetIter<'_, K> {
+        SetIter {
+            iter: self.map.iter(),
+        }
+    }
+    /// retains only the elements for which the predicate returns true
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K) -> bool,
+    {
+        self.map.retain(|key, _| f(key))
+    }
+}
+
+impl<K, S> WrapperSet<K, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+    /// reserves capacity for at least the given number of additional elements
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+    /// inserts the value into the set, returning true if it was not already present; an
+    /// existing element is kept
+    pub fn insert(&mut self, value: K) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+    /// inserts the value into the set, returning the replaced element, if any
+    pub fn replace(&mut self, value: K) -> Option<K> {
+        let previous = self.map.remove_entry(value.as_inner()).map(|(key, _)| key);
+        self.map.insert(value, ());
+        previous
+    }
+    /// returns true if the set contains an element equivalent to the query
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+    /// returns a reference to the element equivalent to the query, if any
+    pub fn get<Q>(&self, value: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(key, _)| key)
+    }
+    /// returns a reference to the element equivalent to the query, inserting the wrapped
+    /// query if it is not present
+    pub fn get_or_insert_ref<'b, Q>(&mut self, value: &'b Q) -> &K
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+        K::Inner: From<&'b Q>,
+    {
+        if let EntryRef::Vacant(entry) = self.map.entry_ref(value) {
+            entry.insert(());
+        }
+        self.get(value).expect("the element was inserted")
+    }
+    /// removes the element equivalent to the query, returning true if it was present
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+    /// removes and returns the element equivalent to the query, if any
+    pub fn take<Q>(&mut self, value: &Q) -> Option<K>
+    where
+        Q: Hash + Equivalent<K::Inner> + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(key, _)| key)
+    }
+    /// returns the hash of the given query, as computed by the set
+    pub fn hash_one<Q>(&self, value: &Q) -> u64
+    where
+        Q: Hash + ?Sized,
+    {
+        self.map.hash_one(value)
+    }
+    /// returns the element with the given hash satisfying the predicate, if any; the hash must
+    /// be computed by [`hash_one`](Self::hash_one) using a value equivalent to the element
+    pub fn find_by_hash<F>(&self, hash: u64, is_match: F) -> Option<&K>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        self.map.find_by_hash(hash, is_match).map(|(key, _)| key)
+    }
+}
+
+impl<K, S> Clone for WrapperSet<K, S>
+where
+    K: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K, S> Default for WrapperSet<K, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: WrapperMap::default(),
+        }
+    }
+}
+
+impl<K, S> core::fmt::Debug for WrapperSet<K, S>
+where
+    K: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K, S> PartialEq for WrapperSet<K, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K, S> Eq for WrapperSet<K, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+impl<K, S> Extend<K> for WrapperSet<K, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = K>,
+    {
+        self.map.extend(iter.into_iter().map(|key| (key, ())))
+    }
+}
+
+impl<K, S> FromIterator<K> for WrapperSet<K, S>
+where
+    K: Newtype,
+    K::Inner: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K, S> IntoIterator for WrapperSet<K, S> {
+    type Item = K;
+    type IntoIter = SetIntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SetIntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, S> IntoIterator for &'a WrapperSet<K, S> {
+    type Item = &'a K;
+    type IntoIter = SetIter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K> Iterator for SetIter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K> Iterator for SetIntoIter<K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for SetIter<'_, K> {}
+
+impl<K> ExactSizeIterator for SetIntoIter<K> {}
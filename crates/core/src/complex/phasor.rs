@@ -0,0 +1,141 @@
+/*
+    Appellation: phasor <module>
+    Created At: 2026.10.19:20:12:37
+    Contrib: @FL03
+*/
+use super::Complex;
+use crate::traits::{Newtype, Transparent};
+use contained_macros::{binary_wrapper, unary_wrapper};
+
+/// The [`Phasor`] wrapper represents a sinusoid using its complex amplitude, e.g.
+/// `Phasor<Complex<f64>>`. Phasors may be added, subtracted, multiplied and divided with one
+/// another as well as with real scalars, which combine with the amplitude as a complex value
+/// with no imaginary part; i.e. scaling a phasor by a positive real preserves its phase.
+///
+/// ```rust
+/// use contained_core::complex::{Complex, Phasor};
+///
+/// let v = Phasor::from_rect(3.0, 4.0);
+/// assert_eq!(v * 2.0, Phasor(Complex::new(6.0, 8.0)));
+/// assert_eq!(1.0 - v, Phasor(Complex::new(-2.0, -4.0)));
+/// assert_eq!(v * v.conj(), Phasor(Complex::new(25.0, 0.0)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct Phasor<T>(pub T);
+
+impl<T> Phasor<T> {
+    /// returns a new instance with the given value
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+    /// returns a mutable reference to the inner value
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+    /// consumes the current instance to return the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Phasor<Complex<T>> {
+    /// returns a new phasor from the real and imaginary parts of its amplitude
+    pub const fn from_rect(re: T, im: T) -> Self {
+        Self(Complex::new(re, im))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Phasor<Complex<T>>
+where
+    T: num_traits::Float,
+{
+    /// returns a new phasor with the given magnitude and phase, in radians
+    pub fn from_polar(magnitude: T, phase: T) -> Self {
+        Self(Complex::from_polar(magnitude, phase))
+    }
+    /// returns the magnitude and phase of the phasor, in radians
+    pub fn to_polar(&self) -> (T, T) {
+        self.0.to_polar()
+    }
+}
+
+impl<T> Newtype for Phasor<T> {
+    type Inner = T;
+
+    const NAME: &'static str = "Phasor";
+
+    fn from_inner(inner: T) -> Self {
+        Self(inner)
+    }
+
+    fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+unsafe impl<T> Transparent for Phasor<T> {}
+
+impl<T> From<T> for Phasor<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::fmt::Display for Phasor<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+binary_wrapper! {
+    impl Phasor {
+        Add.add,
+        Sub.sub,
+        Mul.mul,
+        Div.div,
+        Add.add(scalar f32, f64),
+        Sub.sub(scalar f32, f64),
+        Mul.mul(scalar f32, f64),
+        Div.div(scalar f32, f64),
+    }
+}
+
+unary_wrapper! {
+    impl Phasor {
+        Neg.neg,
+    }
+}
+
+complex_wrapper! {
+    impl Phasor<T> {
+        conj,
+    }
+}
+
+#[cfg(feature = "std")]
+complex_wrapper! {
+    impl Phasor<T> {
+        norm,
+        arg,
+        powf,
+    }
+}
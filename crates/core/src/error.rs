@@ -4,12 +4,37 @@
 */
 //! this module defines the [`Error`] enum and related types for error handling within the
 //! crate.
+//!
+//! Every error belongs to an [`ErrorKind`], making it possible to branch on the category of a
+//! failure without matching the (non-exhaustive) set of variants. Context may be attached to
+//! any fallible result using the [`ResultExt`] extension; with the `alloc` feature the
+//! original error is retained as the [`source`](core::error::Error::source) of the new one,
+//! while builds without an allocator degrade to a chain of static messages ending in the
+//! [`ErrorKind`] of the original error.
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, string::String};
+use alloc::{borrow::Cow, boxed::Box, string::String};
 
 /// a type alias for a [`Result`](core::result::Result) configured to use the custom [`Error`] type.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// the message attached to an error; owned or borrowed with the `alloc` feature and a
+/// static string slice otherwise.
+#[cfg(feature = "alloc")]
+pub type Message = Cow<'static, str>;
+/// the message attached to an error; owned or borrowed with the `alloc` feature and a
+/// static string slice otherwise.
+#[cfg(not(feature = "alloc"))]
+pub type Message = &'static str;
+
+/// the source retained by the [`Context`](Error::Context) variant; the original error with
+/// the `alloc` feature and its [`ErrorKind`] otherwise.
+#[cfg(feature = "alloc")]
+pub type Source = Box<Error>;
+/// the source retained by the [`Context`](Error::Context) variant; the original error with
+/// the `alloc` feature and its [`ErrorKind`] otherwise.
+#[cfg(not(feature = "alloc"))]
+pub type Source = ErrorKind;
+
 /// The [`Error`] implementation defines the possible errors that can occur within the crate.
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -18,9 +43,52 @@ pub enum Error {
     #[error(transparent)]
     AddrParseError(#[from] core::net::AddrParseError),
     #[error(transparent)]
+    BorrowError(#[from] core::cell::BorrowError),
+    #[error(transparent)]
+    BorrowMutError(#[from] core::cell::BorrowMutError),
+    #[error(transparent)]
     FmtError(#[from] core::fmt::Error),
     #[error(transparent)]
+    ParseBoolError(#[from] core::str::ParseBoolError),
+    #[error(transparent)]
+    ParseCharError(#[from] core::char::ParseCharError),
+    #[error(transparent)]
+    ParseFloatError(#[from] core::num::ParseFloatError),
+    #[error(transparent)]
+    ParseIntError(#[from] core::num::ParseIntError),
+    #[error(transparent)]
+    TryFromIntError(#[from] core::num::TryFromIntError),
+    #[error(transparent)]
     Utf8Error(#[from] core::str::Utf8Error),
+    #[error("the value is NaN")]
+    NaN,
+    #[error("the lock was poisoned by a panicking thread")]
+    Poisoned,
+    /// a value could not be converted from one type into another
+    #[error("unable to convert `{from}` into `{into}`")]
+    Conversion {
+        from: &'static str,
+        into: &'static str,
+    },
+    /// the input could not be parsed into the target type
+    #[error("unable to parse `{target}`: {reason}")]
+    Parse {
+        target: &'static str,
+        reason: Message,
+    },
+    /// a value was rejected by the validation of the named wrapper
+    #[error("the value is not a valid `{wrapper}`: {reason}")]
+    Validation {
+        wrapper: &'static str,
+        reason: Message,
+    },
+    /// an error annotated with additional context
+    #[error("{context}")]
+    Context {
+        context: Message,
+        #[source]
+        source: Source,
+    },
     // std-dependent errors
     #[cfg(feature = "std")]
     #[error(transparent)]
@@ -32,8 +100,66 @@ pub enum Error {
     #[cfg(feature = "alloc")]
     #[error("Unknown Error: {0}")]
     Unknown(String),
+    // json-dependent variants
+    #[cfg(feature = "json")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// [`ErrorKind`] enumerates the categories of an [`Error`], allowing callers to match on the
+/// nature of a failure rather than its exact variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// a `RefCell` was already borrowed
+    Borrow,
+    /// a value could not be converted into another type
+    Conversion,
+    /// a formatter failed to write its output
+    Fmt,
+    /// an I/O operation failed
+    Io,
+    /// a value could not be (de)serialized as JSON
+    Json,
+    /// a floating-point value was `NaN`
+    NaN,
+    /// an input could not be parsed
+    Parse,
+    /// a lock was poisoned by a panicking thread
+    Poisoned,
+    /// a value was rejected by a validating wrapper
+    Validation,
+    /// any other error
+    Other,
 }
 
+impl ErrorKind {
+    /// returns a static description of the kind
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Borrow => "a value was already borrowed",
+            Self::Conversion => "a conversion failed",
+            Self::Fmt => "a formatter returned an error",
+            Self::Io => "an I/O operation failed",
+            Self::Json => "a JSON (de)serialization failed",
+            Self::NaN => "the value is NaN",
+            Self::Parse => "the input could not be parsed",
+            Self::Poisoned => "the lock was poisoned by a panicking thread",
+            Self::Validation => "the value failed validation",
+            Self::Other => "an unknown error occurred",
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for ErrorKind {}
+
 impl Error {
     #[cfg(feature = "alloc")]
     /// a functional constructor for the [`BoxError`](Self::BoxError) variant
@@ -51,6 +177,102 @@ impl Error {
     {
         Self::Unknown(message.to_string())
     }
+    /// a functional constructor for the [`Conversion`](Self::Conversion) variant, naming the
+    /// source and target types
+    pub fn conversion<A, B>() -> Self
+    where
+        A: ?Sized,
+        B: ?Sized,
+    {
+        Self::Conversion {
+            from: core::any::type_name::<A>(),
+            into: core::any::type_name::<B>(),
+        }
+    }
+    /// a functional constructor for the [`Parse`](Self::Parse) variant
+    pub fn parse<M>(target: &'static str, reason: M) -> Self
+    where
+        M: Into<Message>,
+    {
+        Self::Parse {
+            target,
+            reason: reason.into(),
+        }
+    }
+    /// a functional constructor for the [`Validation`](Self::Validation) variant
+    pub fn validation<M>(wrapper: &'static str, reason: M) -> Self
+    where
+        M: Into<Message>,
+    {
+        Self::Validation {
+            wrapper,
+            reason: reason.into(),
+        }
+    }
+    /// a functional constructor for the [`Validation`](Self::Validation) variant describing
+    /// the error returned by a checked constructor; without the `alloc` feature the reason
+    /// degrades to a static message.
+    pub fn rejected<E>(wrapper: &'static str, error: E) -> Self
+    where
+        E: core::fmt::Display,
+    {
+        #[cfg(feature = "alloc")]
+        let reason = alloc::string::ToString::to_string(&error);
+        #[cfg(not(feature = "alloc"))]
+        let reason = {
+            let _ = error;
+            "the value was rejected"
+        };
+        Self::validation(wrapper, reason)
+    }
+    /// wraps the error with the given context, keeping the original as its source; without
+    /// the `alloc` feature only the [`ErrorKind`] of the original is retained.
+    pub fn context<M>(self, context: M) -> Self
+    where
+        M: Into<Message>,
+    {
+        #[cfg(feature = "alloc")]
+        let source = Box::new(self);
+        #[cfg(not(feature = "alloc"))]
+        let source = self.kind();
+        Self::Context {
+            context: context.into(),
+            source,
+        }
+    }
+    /// returns the [`ErrorKind`] of the error; errors wrapped with context report the kind
+    /// of their source.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::AddrParseError(_)
+            | Self::ParseBoolError(_)
+            | Self::ParseCharError(_)
+            | Self::ParseFloatError(_)
+            | Self::ParseIntError(_)
+            | Self::Utf8Error(_)
+            | Self::Parse { .. } => ErrorKind::Parse,
+            Self::BorrowError(_) | Self::BorrowMutError(_) => ErrorKind::Borrow,
+            Self::FmtError(_) => ErrorKind::Fmt,
+            Self::TryFromIntError(_) | Self::Conversion { .. } => ErrorKind::Conversion,
+            Self::NaN => ErrorKind::NaN,
+            Self::Poisoned => ErrorKind::Poisoned,
+            Self::Validation { .. } => ErrorKind::Validation,
+            #[cfg(feature = "alloc")]
+            Self::Context { source, .. } => source.kind(),
+            #[cfg(not(feature = "alloc"))]
+            Self::Context { source, .. } => *source,
+            #[cfg(feature = "std")]
+            Self::IOError(_) => ErrorKind::Io,
+            #[cfg(feature = "alloc")]
+            Self::BoxError(_) | Self::Unknown(_) => ErrorKind::Other,
+            #[cfg(feature = "json")]
+            Self::Json(_) => ErrorKind::Json,
+        }
+    }
+    /// returns true if the error is of the given kind
+    pub fn is(&self, kind: ErrorKind) -> bool {
+        self.kind() == kind
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -66,3 +288,51 @@ impl From<String> for Error {
         Self::Unknown(value)
     }
 }
+
+impl From<core::convert::Infallible> for Error {
+    fn from(value: core::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::sync::PoisonError<T>> for Error {
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Self::Poisoned
+    }
+}
+
+/// [`ResultExt`] extends any [`Result`](core::result::Result) whose error converts into an
+/// [`Error`] with methods for attaching context to the failure.
+pub trait ResultExt<T> {
+    /// converts the error into an [`Error`] wrapped with the given context
+    fn context<M>(self, context: M) -> Result<T>
+    where
+        M: Into<Message>;
+    /// converts the error into an [`Error`] wrapped with the context produced by `f`, which
+    /// is only invoked on failure
+    fn with_context<M, F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> M,
+        M: Into<Message>;
+}
+
+impl<T, E> ResultExt<T> for core::result::Result<T, E>
+where
+    E: Into<Error>,
+{
+    fn context<M>(self, context: M) -> Result<T>
+    where
+        M: Into<Message>,
+    {
+        self.map_err(|err| err.into().context(context))
+    }
+
+    fn with_context<M, F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> M,
+        M: Into<Message>,
+    {
+        self.map_err(|err| err.into().context(f()))
+    }
+}
@@ -0,0 +1,53 @@
+/*
+    Appellation: ext <module>
+    Created At: 2026.10.19:16:02:37
+    Contrib: @FL03
+*/
+use crate::error::Result;
+use crate::traits::Newtype;
+use alloc::string::String;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// The [`JsonExt`] trait extends serializable wrappers with methods converting them to and
+/// from JSON, surfacing any failure as an [`Error::Json`](crate::Error::Json).
+///
+/// ```rust
+/// use contained_core::json::JsonExt;
+/// use contained_core::wrappers::TotalOrd;
+///
+/// let value = TotalOrd(1.5f64);
+/// assert_eq!(value.to_json().unwrap(), "1.5");
+/// assert_eq!(TotalOrd::<f64>::from_json("1.5").unwrap(), value);
+/// ```
+pub trait JsonExt: Newtype + Serialize {
+    /// serializes the wrapper into a JSON string
+    fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    /// serializes the wrapper into a pretty-printed JSON string
+    fn to_json_pretty(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(Into::into)
+    }
+    /// serializes the wrapper into a JSON [`Value`]
+    fn to_json_value(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+    /// deserializes an instance of the wrapper from the given JSON string
+    fn from_json(json: &str) -> Result<Self>
+    where
+        Self: DeserializeOwned,
+    {
+        serde_json::from_str(json).map_err(Into::into)
+    }
+    /// deserializes an instance of the wrapper from the given JSON [`Value`]
+    fn from_json_value(value: Value) -> Result<Self>
+    where
+        Self: DeserializeOwned,
+    {
+        serde_json::from_value(value).map_err(Into::into)
+    }
+}
+
+impl<W> JsonExt for W where W: Newtype + Serialize {}
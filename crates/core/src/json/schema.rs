@@ -0,0 +1,143 @@
+/*
+    Appellation: schema <module>
+    Created At: 2026.10.19:16:09:15
+    Contrib: @FL03
+*/
+//! this module defines the [`JsonSchema`] trait along with the functions used to compose the
+//! schemas of wrappers with respect to their serialized representation.
+use crate::traits::Newtype;
+use crate::wrappers::{NotNan, TotalOrd};
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde_json::{Map, Value, json};
+
+/// The [`JsonSchema`] trait describes the JSON representation of a type as a fragment of a
+/// [JSON Schema](https://json-schema.org/). The `Wrapper` derive implements the trait through
+/// `#[wrap(schema(...))]`, composing the schema of the field with any validation bounds.
+pub trait JsonSchema {
+    /// returns the schema describing the type
+    fn json_schema() -> Value;
+}
+
+/// returns the schema of a wrapper represented by its inner value
+pub fn transparent<W>(inner: Value) -> Value
+where
+    W: Newtype,
+{
+    inner
+}
+
+/// returns the schema of a wrapper whose inner value is tagged with its name
+pub fn tagged<W>(inner: Value) -> Value
+where
+    W: Newtype,
+{
+    object(W::NAME, inner)
+}
+
+/// returns the schema of a wrapper represented as a struct with a single field
+pub fn structured<W>(inner: Value) -> Value
+where
+    W: Newtype,
+{
+    let mut schema = object(W::FIELD, inner);
+    schema["title"] = Value::from(W::NAME);
+    schema
+}
+
+/// extends the given schema with the given keywords, e.g. `minimum`, replacing any existing
+/// values
+pub fn bound<I>(mut schema: Value, keywords: I) -> Value
+where
+    I: IntoIterator<Item = (&'static str, Value)>,
+{
+    if let Value::Object(map) = &mut schema {
+        for (key, value) in keywords {
+            map.insert(String::from(key), value);
+        }
+    }
+    schema
+}
+
+/// returns the schema of an object with a single, required property
+fn object(key: &str, value: Value) -> Value {
+    let mut properties = Map::new();
+    properties.insert(String::from(key), value);
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": [key],
+        "additionalProperties": false,
+    })
+}
+
+macro_rules! impl_json_schema {
+    (@int $($t:ty),* $(,)?) => {
+        $(
+            impl JsonSchema for $t {
+                fn json_schema() -> Value {
+                    json!({ "type": "integer", "minimum": <$t>::MIN, "maximum": <$t>::MAX })
+                }
+            }
+        )*
+    };
+    ($($t:ty => $schema:tt),* $(,)?) => {
+        $(
+            impl JsonSchema for $t {
+                fn json_schema() -> Value {
+                    json!($schema)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema! { @int i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+
+impl_json_schema! {
+    i128 => { "type": "integer" },
+    u128 => { "type": "integer", "minimum": 0 },
+    f32 => { "type": "number" },
+    f64 => { "type": "number" },
+    bool => { "type": "boolean" },
+    char => { "type": "string", "minLength": 1, "maxLength": 1 },
+    str => { "type": "string" },
+    String => { "type": "string" },
+    () => { "type": "null" },
+}
+
+impl<T> JsonSchema for Option<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "anyOf": [T::json_schema(), { "type": "null" }] })
+    }
+}
+
+impl<T> JsonSchema for Vec<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T> JsonSchema for TotalOrd<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for NotNan<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
@@ -25,6 +25,8 @@ pub(crate) mod macros {
     #[macro_use]
     pub mod seal;
     #[macro_use]
+    pub mod complex;
+    #[macro_use]
     pub mod format;
     #[macro_use]
     pub mod wrapper;
@@ -32,20 +34,184 @@ pub(crate) mod macros {
 // modules
 pub mod error;
 
+#[cfg(all(feature = "alloc", feature = "hashbrown"))]
+pub mod collections {
+    //! hash maps and sets keyed by wrappers, queried using the inner values of their keys
+    #[doc(inline)]
+    pub use self::{
+        entry::*,
+        map::{IntoIter, Iter, IterMut, WrapperMap},
+        set::*,
+    };
+    #[doc(no_inline)]
+    pub use hashbrown::{DefaultHashBuilder, Equivalent};
+
+    mod entry;
+    mod key;
+    mod map;
+    mod set;
+}
+
+#[cfg(feature = "complex")]
+pub mod complex {
+    //! wrappers over complex numbers, featuring the [`Phasor`] wrapper supporting mixed
+    //! real and complex arithmetic
+    #[doc(inline)]
+    pub use self::phasor::*;
+    #[doc(no_inline)]
+    pub use num_complex::{Complex, Complex32, Complex64};
+
+    mod phasor;
+}
+
+pub mod cell {
+    //! interior-mutability wrappers sharing the uniform
+    //! [`InteriorMut`](crate::traits::InteriorMut) interface
+    #[doc(inline)]
+    pub use self::local::*;
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use self::shared::*;
+    #[doc(inline)]
+    #[cfg(feature = "std")]
+    pub use self::sync::*;
+
+    mod local;
+    #[cfg(feature = "alloc")]
+    mod shared;
+    #[cfg(feature = "std")]
+    mod sync;
+}
+
 pub mod traits {
     //! core traits and interfaces for wrappers and their operations, formatting, etc.
     #[doc(inline)]
-    pub use self::{get::*, wrapper::*};
+    pub use self::{atomic::*, float::*, get::*, interior::*, key::*, newtype::*, wrapper::*};
 
+    mod atomic;
+    mod float;
     mod get;
+    mod interior;
+    mod key;
+    mod newtype;
     mod wrapper;
 }
+
+#[cfg(feature = "json")]
+pub mod json {
+    //! helpers for converting wrappers to and from JSON, along with the [`JsonSchema`] trait
+    //! describing the JSON representation of a type
+    #[doc(inline)]
+    pub use self::{ext::*, schema::JsonSchema};
+    #[doc(no_inline)]
+    pub use serde_json::{Map, Value, json};
+
+    pub mod schema;
+
+    mod ext;
+}
+
+#[cfg(all(feature = "alloc", feature = "rayon"))]
+pub mod par {
+    //! parallel iteration over collections of wrappers, along with the re-exported `rayon`
+    //! traits used by the `Wrapper` derive through `#[wrap(par)]`
+    #[doc(inline)]
+    pub use self::ext::*;
+    #[doc(no_inline)]
+    pub use rayon::iter::{
+        FromParallelIterator, IndexedParallelIterator, IntoParallelIterator,
+        IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelExtend, ParallelIterator,
+    };
+
+    mod ext;
+}
+
+#[cfg(feature = "rand")]
+pub mod rand {
+    //! random sampling of wrappers, used by the `Wrapper` derive through `#[wrap(rand)]`
+    #[doc(inline)]
+    pub use self::sample::*;
+    #[doc(no_inline)]
+    pub use ::rand::distr::uniform::{SampleRange, SampleUniform};
+    #[doc(no_inline)]
+    pub use ::rand::distr::{Distribution, StandardUniform, Uniform};
+    #[doc(no_inline)]
+    pub use ::rand::{Rng, RngExt, SeedableRng};
+
+    mod sample;
+}
+
+#[cfg(feature = "serde")]
+pub mod serde {
+    //! adapters (de)serializing [`Newtype`](crate::traits::Newtype) wrappers, usable as
+    //! `#[serde(with = "...")]` modules on fields of foreign wrapper types
+    //!
+    //! - [`transparent`]: the wrapper is represented by its inner value, i.e. `5`
+    //! - [`tagged`]: the inner value is tagged with the name of the wrapper, i.e.
+    //!   `{"Name": 5}`
+    //! - [`structured`]: the wrapper is represented as a struct with a single field, i.e.
+    //!   `{"value": 5}`
+    #[doc(no_inline)]
+    pub use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+
+    pub mod structured;
+    pub mod tagged;
+    pub mod transparent;
+
+    mod ident;
+}
+
+pub mod units {
+    //! compile-time units of measure, featuring the [`Quantity`] wrapper along with the
+    //! type-level dimensions and units it is parameterized by
+    #[doc(inline)]
+    pub use self::{dim::*, quantity::*, unit::*};
+
+    pub mod dim;
+    mod quantity;
+    mod unit;
+}
+
+#[cfg(feature = "wasm_bindgen")]
+pub mod wasm {
+    //! the ABI conversions of [`wasm_bindgen`] used to pass wrappers across the JS boundary
+    #[doc(no_inline)]
+    pub use wasm_bindgen::convert::{
+        FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, WasmAbi,
+    };
+    #[doc(no_inline)]
+    pub use wasm_bindgen::describe::WasmDescribe;
+}
+
+pub mod wrappers {
+    //! specialized wrapper types extending the functionality of their inner values
+    #[doc(inline)]
+    pub use self::{atomic::*, float::*, key::*, lazy::*, memo::*};
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use self::{history::*, observe::*};
+
+    mod atomic;
+    mod float;
+    mod key;
+    mod lazy;
+    mod memo;
+
+    #[cfg(feature = "alloc")]
+    mod history;
+    #[cfg(feature = "alloc")]
+    mod observe;
+}
 // re-exports
 #[doc(inline)]
-pub use self::error::{Error, Result};
+pub use self::error::{Error, ErrorKind, Result, ResultExt};
+#[doc(no_inline)]
+pub use num_traits;
 // prelude
 #[doc(hidden)]
 pub mod prelude {
     #[cfg(feature = "macros")]
     pub use crate::{fmt_wrapper, wrapper};
+    #[cfg(all(feature = "complex", feature = "macros"))]
+    pub use crate::complex_wrapper;
 }
@@ -0,0 +1,94 @@
+/*
+    appellation: complex <module>
+    authors: @FL03
+*/
+#![cfg(feature = "complex")]
+
+/// A macro forwarding the inherent methods of [`Complex`](crate::complex::Complex) onto
+/// wrappers of complex values, implementing each of them for `W<Complex<T>>`.
+///
+/// For tuple structs, use the following:
+///
+/// ```ignore
+/// complex_wrapper! {
+///     impl WrapperType<T> {
+///         conj,
+///         norm,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// For structs with named fields, use the following syntax, replacing `field` with the actual field name:
+///
+/// ```ignore
+/// complex_wrapper! {
+///     impl WrapperType<T>.field {
+///         conj,
+///         norm,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// The supported methods are `conj`, returning the conjugate as another wrapper, `norm` and
+/// `arg`, returning the modulus and argument of the value as reals, and `powf`, raising the
+/// value to a real power. Each of the methods other than `conj` relies upon the floating-point
+/// functions of `num-complex`, which are only available alongside the `std` feature.
+#[macro_export]
+macro_rules! complex_wrapper {
+    (impl $s:ident<$T:ident>.$field:ident { $($method:ident),* $(,)? }) => {
+        $(
+            $crate::complex_wrapper!(@impl $s<$T>::$method.$field);
+        )*
+    };
+    (impl $s:ident<$T:ident> { $($method:ident),* $(,)? }) => {
+        $(
+            $crate::complex_wrapper!(@impl $s<$T>::$method.0);
+        )*
+    };
+    (@impl $s:ident<$T:ident>::conj.$field:tt) => {
+        impl<$T> $s<$crate::complex::Complex<$T>>
+        where
+            $T: Clone + $crate::num_traits::Num + ::core::ops::Neg<Output = $T>,
+        {
+            /// returns the complex conjugate of the wrapped value
+            pub fn conj(&self) -> Self {
+                $s { $field: self.$field.conj() }
+            }
+        }
+    };
+    (@impl $s:ident<$T:ident>::norm.$field:tt) => {
+        impl<$T> $s<$crate::complex::Complex<$T>>
+        where
+            $T: $crate::num_traits::Float,
+        {
+            /// returns the modulus, or magnitude, of the wrapped value
+            pub fn norm(&self) -> $T {
+                self.$field.norm()
+            }
+        }
+    };
+    (@impl $s:ident<$T:ident>::arg.$field:tt) => {
+        impl<$T> $s<$crate::complex::Complex<$T>>
+        where
+            $T: $crate::num_traits::Float,
+        {
+            /// returns the argument, or phase, of the wrapped value in radians
+            pub fn arg(&self) -> $T {
+                self.$field.arg()
+            }
+        }
+    };
+    (@impl $s:ident<$T:ident>::powf.$field:tt) => {
+        impl<$T> $s<$crate::complex::Complex<$T>>
+        where
+            $T: $crate::num_traits::Float,
+        {
+            /// raises the wrapped value to the given real power
+            pub fn powf(&self, exp: $T) -> Self {
+                $s { $field: self.$field.powf(exp) }
+            }
+        }
+    };
+}
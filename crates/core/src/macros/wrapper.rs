@@ -8,21 +8,40 @@
 #[macro_export]
 macro_rules! wrapper {
     ($($(#[$meta:meta])* $S:ident($vis:vis $T:ident) $(where $($rest:tt)*)?;),* $(,)?) => {
-        $($crate::wrapper! { @impl $(#[$meta])* $S($vis $T) $(where $($rest)*)? };)*
+        $($crate::wrapper! { @impl $(#[$meta])* $S($vis $T) $(where $($rest)*)?; })*
     };
     (@impl
         $(#[$meta:meta])*
         $S:ident($vis:vis $T:ident) $(where $($rest:tt)*)?;
     ) => {
-        #[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd, $($derive),*)]
-        #[cfg_attr(
-            feature = "serde",
-            derive(serde::Deserialize, serde::Serialize),
-            serde(default, transparent),
-        )]
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
         #[repr(transparent)]
         pub struct $S<$T>($vis $T) $(where $($rest)*)?;
 
+        impl<$T> $crate::traits::Newtype for $S<$T> {
+            type Inner = $T;
+
+            const NAME: &'static str = stringify!($S);
+
+            fn from_inner(inner: $T) -> Self {
+                Self(inner)
+            }
+
+            fn as_inner(&self) -> &$T {
+                &self.0
+            }
+
+            fn into_inner(self) -> $T {
+                self.0
+            }
+        }
+
+        unsafe impl<$T> $crate::traits::Transparent for $S<$T> {}
+
+        // the `serde` feature is resolved here, within this crate, rather than by the caller
+        $crate::__wrapper_serde! { $S<$T> }
+
         impl<$T> $S<$T> {
             /// returns a new instance with the given value
             pub const fn new(value: $T) -> Self {
@@ -130,3 +149,41 @@ macro_rules! wrapper {
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "serde")]
+macro_rules! __wrapper_serde {
+    ($S:ident<$T:ident>) => {
+        impl<$T> $crate::serde::Serialize for $S<$T>
+        where
+            $T: $crate::serde::Serialize,
+        {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: $crate::serde::Serializer,
+            {
+                $crate::serde::transparent::serialize(self, serializer)
+            }
+        }
+
+        impl<'de, $T> $crate::serde::Deserialize<'de> for $S<$T>
+        where
+            $T: $crate::serde::Deserialize<'de>,
+        {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: $crate::serde::Deserializer<'de>,
+            {
+                $crate::serde::transparent::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "serde"))]
+macro_rules! __wrapper_serde {
+    ($S:ident<$T:ident>) => {};
+}
@@ -0,0 +1,183 @@
+/*
+    Appellation: ext <module>
+    Created At: 2026.10.19:18:12:05
+    Contrib: @FL03
+*/
+use crate::traits::{Newtype, Transparent};
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+use rayon::prelude::*;
+
+/// The [`ParWrapVec`] trait provides parallel conversions between a vector of wrappers and a
+/// vector of their inner values, consuming the vector.
+///
+/// ```rust
+/// use contained_core::par::ParWrapVec;
+/// use contained_core::wrappers::TotalOrd;
+///
+/// let wrapped: Vec<TotalOrd<f64>> = vec![1.0, 2.0, 3.0].par_wrap();
+/// assert_eq!(wrapped.par_map_inner(|x| x * 2.0), vec![2.0, 4.0, 6.0]);
+/// ```
+pub trait ParWrapVec<T> {
+    /// wraps each of the values, in parallel, collecting them into a newly allocated vector;
+    /// [`Transparent`] wrappers should prefer [`wrap_in_place`](Self::wrap_in_place), which
+    /// reuses the allocation without copying
+    fn par_wrap<W>(self) -> Vec<W>
+    where
+        W: Newtype<Inner = T> + Send;
+    /// unwraps each of the wrappers, in parallel, collecting them into a newly allocated
+    /// vector; [`Transparent`] wrappers should prefer
+    /// [`unwrap_in_place`](Self::unwrap_in_place), which reuses the allocation without copying
+    fn par_unwrap(self) -> Vec<T::Inner>
+    where
+        T: Newtype,
+        T::Inner: Send;
+    /// applies the function to the inner value of each wrapper, in parallel
+    fn par_map_inner<U, F>(self, f: F) -> Vec<U>
+    where
+        T: Newtype,
+        F: Fn(T::Inner) -> U + Send + Sync,
+        U: Send;
+    /// reinterprets the values as wrappers without copying
+    fn wrap_in_place<W>(self) -> Vec<W>
+    where
+        W: Transparent<Inner = T>;
+    /// reinterprets the wrappers as their inner values without copying
+    fn unwrap_in_place(self) -> Vec<T::Inner>
+    where
+        T: Transparent;
+}
+
+/// The [`ParWrapSlice`] trait provides parallel conversions between a slice of wrappers and a
+/// vector of their inner values, cloning the elements as needed.
+pub trait ParWrapSlice<T> {
+    /// clones and wraps each of the values, in parallel
+    fn par_wrap<W>(&self) -> Vec<W>
+    where
+        T: Clone,
+        W: Newtype<Inner = T> + Send;
+    /// clones and unwraps each of the wrappers, in parallel
+    fn par_unwrap(&self) -> Vec<T::Inner>
+    where
+        T: Newtype,
+        T::Inner: Clone + Send;
+    /// applies the function to a reference of the inner value of each wrapper, in parallel
+    fn par_map_inner<U, F>(&self, f: F) -> Vec<U>
+    where
+        T: Newtype,
+        T::Inner: Sync,
+        F: Fn(&T::Inner) -> U + Send + Sync,
+        U: Send;
+    /// reinterprets the wrappers as a slice of their inner values without copying
+    fn as_inner_slice(&self) -> &[T::Inner]
+    where
+        T: Transparent;
+    /// reinterprets the wrappers as a mutable slice of their inner values without copying
+    fn as_inner_slice_mut(&mut self) -> &mut [T::Inner]
+    where
+        T: Transparent;
+}
+
+impl<T> ParWrapVec<T> for Vec<T>
+where
+    T: Send,
+{
+    fn par_wrap<W>(self) -> Vec<W>
+    where
+        W: Newtype<Inner = T> + Send,
+    {
+        self.into_par_iter().map(W::from_inner).collect()
+    }
+
+    fn par_unwrap(self) -> Vec<T::Inner>
+    where
+        T: Newtype,
+        T::Inner: Send,
+    {
+        self.into_par_iter().map(T::into_inner).collect()
+    }
+
+    fn par_map_inner<U, F>(self, f: F) -> Vec<U>
+    where
+        T: Newtype,
+        F: Fn(T::Inner) -> U + Send + Sync,
+        U: Send,
+    {
+        self.into_par_iter().map(|w| f(w.into_inner())).collect()
+    }
+
+    fn wrap_in_place<W>(self) -> Vec<W>
+    where
+        W: Transparent<Inner = T>,
+    {
+        // SAFETY: `W` is guaranteed to share the layout of `T`, and accept any of its values
+        unsafe { transmute_vec(self) }
+    }
+
+    fn unwrap_in_place(self) -> Vec<T::Inner>
+    where
+        T: Transparent,
+    {
+        // SAFETY: `T` is guaranteed to share the layout of its inner value
+        unsafe { transmute_vec(self) }
+    }
+}
+
+impl<T> ParWrapSlice<T> for [T]
+where
+    T: Sync,
+{
+    fn par_wrap<W>(&self) -> Vec<W>
+    where
+        T: Clone,
+        W: Newtype<Inner = T> + Send,
+    {
+        self.par_iter().map(|v| W::from_inner(v.clone())).collect()
+    }
+
+    fn par_unwrap(&self) -> Vec<T::Inner>
+    where
+        T: Newtype,
+        T::Inner: Clone + Send,
+    {
+        self.par_iter().map(|w| w.as_inner().clone()).collect()
+    }
+
+    fn par_map_inner<U, F>(&self, f: F) -> Vec<U>
+    where
+        T: Newtype,
+        T::Inner: Sync,
+        F: Fn(&T::Inner) -> U + Send + Sync,
+        U: Send,
+    {
+        self.par_iter().map(|w| f(w.as_inner())).collect()
+    }
+
+    fn as_inner_slice(&self) -> &[T::Inner]
+    where
+        T: Transparent,
+    {
+        // SAFETY: `T` is guaranteed to share the layout of its inner value
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), self.len()) }
+    }
+
+    fn as_inner_slice_mut(&mut self) -> &mut [T::Inner]
+    where
+        T: Transparent,
+    {
+        // SAFETY: `T` is guaranteed to share the layout of its inner value, and accept any of
+        // its values
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), self.len()) }
+    }
+}
+
+/// reinterprets the vector as a vector of another type, reusing its allocation
+///
+/// # Safety
+///
+/// `A` and `B` must share the same layout, and every value of `A` must be a valid `B`
+unsafe fn transmute_vec<A, B>(vec: Vec<A>) -> Vec<B> {
+    let mut vec = ManuallyDrop::new(vec);
+    let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    unsafe { Vec::from_raw_parts(ptr.cast::<B>(), len, cap) }
+}
@@ -0,0 +1,65 @@
+/*
+    Appellation: sample <module>
+    Created At: 2026.10.19:17:05:12
+    Contrib: @FL03
+*/
+use crate::traits::TotalFloat;
+use crate::wrappers::{NotNan, TotalOrd};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+/// the maximum number of samples drawn by [`sample_valid`] before giving up
+pub const MAX_ATTEMPTS: usize = 10_000;
+
+/// returns a generator seeded by the system; the thread-local generator is used whenever the
+/// `std` feature is enabled
+#[cfg(feature = "std")]
+pub fn rng() -> impl Rng {
+    rand::rng()
+}
+
+/// returns a generator seeded by the system; the thread-local generator is used whenever the
+/// `std` feature is enabled
+#[cfg(not(feature = "std"))]
+pub fn rng() -> impl Rng {
+    rand::make_rng::<rand::rngs::SmallRng>()
+}
+
+/// draws samples until one passes the given, checked constructor, allowing bounded or
+/// validated wrappers to only ever produce valid values
+///
+/// # Panics
+///
+/// Panics if none of the first [`MAX_ATTEMPTS`] samples are valid
+pub fn sample_valid<T, W, E, S, V>(mut sample: S, validate: V) -> W
+where
+    S: FnMut() -> T,
+    V: Fn(T) -> Result<W, E>,
+{
+    for _ in 0..MAX_ATTEMPTS {
+        if let Ok(value) = validate(sample()) {
+            return value;
+        }
+    }
+    panic!("failed to sample a valid value within {MAX_ATTEMPTS} attempts")
+}
+
+impl<T> Distribution<TotalOrd<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TotalOrd<T> {
+        TotalOrd(self.sample(rng))
+    }
+}
+
+/// the floats sampled by the [`StandardUniform`] distribution are never `NaN`
+impl<T> Distribution<NotNan<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+    T: TotalFloat,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NotNan<T> {
+        sample_valid(|| self.sample(rng), NotNan::try_new)
+    }
+}
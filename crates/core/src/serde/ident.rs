@@ -0,0 +1,51 @@
+/*
+    Appellation: ident <module>
+    Created At: 2026.10.19:15:14:02
+    Contrib: @FL03
+*/
+use core::fmt;
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+
+/// [`Ident`] deserializes an identifier, i.e. a tag or field name, returning whether it
+/// matches the expected name; identifiers may also be given by their index, `0`.
+pub(crate) struct Ident(pub &'static str);
+
+impl<'de> DeserializeSeed<'de> for Ident {
+    type Value = bool;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for Ident {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the identifier `{}`", self.0)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value == 0)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value == self.0)
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value == self.0.as_bytes())
+    }
+}
@@ -0,0 +1,113 @@
+/*
+    Appellation: structured <module>
+    Created At: 2026.10.19:15:23:31
+    Contrib: @FL03
+*/
+//! (de)serializes a [`Newtype`] as a struct named after the wrapper with a single field,
+//! [`FIELD`](Newtype::FIELD), i.e. `{"value": value}`
+use super::ident::Ident;
+use crate::traits::Newtype;
+use core::fmt::{self, Display};
+use core::marker::PhantomData;
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// serializes the wrapper as a struct with a single field
+pub fn serialize<W, S>(value: &W, serializer: S) -> Result<S::Ok, S::Error>
+where
+    W: Newtype,
+    W::Inner: Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct(W::NAME, 1)?;
+    state.serialize_field(W::FIELD, value.as_inner())?;
+    state.end()
+}
+
+/// deserializes the wrapper from a struct with a single field
+pub fn deserialize<'de, W, D>(deserializer: D) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserialize_inner::<W, D>(deserializer).map(W::from_inner)
+}
+
+/// deserializes the field of the struct before passing it through the given, checked
+/// constructor
+pub fn deserialize_with<'de, W, D, F, E>(deserializer: D, f: F) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+    F: FnOnce(W::Inner) -> Result<W, E>,
+    E: Display,
+{
+    let inner = deserialize_inner::<W, D>(deserializer)?;
+    f(inner).map_err(de::Error::custom)
+}
+
+fn deserialize_inner<'de, W, D>(deserializer: D) -> Result<W::Inner, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(W::NAME, Fields::<W>::LIST, StructVisitor::<W>(PhantomData))
+}
+
+/// a helper providing the `'static` list of fields expected by the deserializer
+struct Fields<W>(PhantomData<W>);
+
+impl<W> Fields<W>
+where
+    W: Newtype,
+{
+    const LIST: &'static [&'static str] = &[W::FIELD];
+}
+
+struct StructVisitor<W>(PhantomData<W>);
+
+impl<'de, W> Visitor<'de> for StructVisitor<W>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+{
+    type Value = W::Inner;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", W::NAME)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match seq.next_element()? {
+            Some(inner) => Ok(inner),
+            None => Err(de::Error::invalid_length(0, &self)),
+        }
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut inner = None;
+        while let Some(matched) = map.next_key_seed(Ident(W::FIELD))? {
+            if !matched {
+                return Err(de::Error::custom(format_args!(
+                    "unknown field, expected `{}`",
+                    W::FIELD
+                )));
+            }
+            if inner.is_some() {
+                return Err(de::Error::duplicate_field(W::FIELD));
+            }
+            inner = Some(map.next_value()?);
+        }
+        inner.ok_or_else(|| de::Error::missing_field(W::FIELD))
+    }
+}
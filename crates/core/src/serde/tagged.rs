@@ -0,0 +1,95 @@
+/*
+    Appellation: tagged <module>
+    Created At: 2026.10.19:15:19:50
+    Contrib: @FL03
+*/
+//! (de)serializes a [`Newtype`] as its inner value tagged with the
+//! [`NAME`](Newtype::NAME) of the wrapper, i.e. `{"Name": value}`
+use super::ident::Ident;
+use crate::traits::Newtype;
+use core::fmt::{self, Display};
+use core::marker::PhantomData;
+use serde::de::{self, EnumAccess, VariantAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// serializes the wrapper as its inner value tagged with the name of the wrapper
+pub fn serialize<W, S>(value: &W, serializer: S) -> Result<S::Ok, S::Error>
+where
+    W: Newtype,
+    W::Inner: Serialize,
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant(W::NAME, 0, W::NAME, value.as_inner())
+}
+
+/// deserializes the wrapper from its inner value, ensuring it is tagged with the name of the
+/// wrapper
+pub fn deserialize<'de, W, D>(deserializer: D) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserialize_inner::<W, D>(deserializer).map(W::from_inner)
+}
+
+/// deserializes the tagged inner value before passing it through the given, checked
+/// constructor
+pub fn deserialize_with<'de, W, D, F, E>(deserializer: D, f: F) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+    F: FnOnce(W::Inner) -> Result<W, E>,
+    E: Display,
+{
+    let inner = deserialize_inner::<W, D>(deserializer)?;
+    f(inner).map_err(de::Error::custom)
+}
+
+fn deserialize_inner<'de, W, D>(deserializer: D) -> Result<W::Inner, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_enum(W::NAME, Tags::<W>::LIST, TaggedVisitor::<W>(PhantomData))
+}
+
+/// a helper providing the `'static` list of tags expected by the deserializer
+struct Tags<W>(PhantomData<W>);
+
+impl<W> Tags<W>
+where
+    W: Newtype,
+{
+    const LIST: &'static [&'static str] = &[W::NAME];
+}
+
+struct TaggedVisitor<W>(PhantomData<W>);
+
+impl<'de, W> Visitor<'de> for TaggedVisitor<W>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+{
+    type Value = W::Inner;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a value tagged with `{}`", W::NAME)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (matched, variant) = data.variant_seed(Ident(W::NAME))?;
+        if !matched {
+            return Err(de::Error::custom(format_args!(
+                "expected a value tagged with `{}`",
+                W::NAME
+            )));
+        }
+        variant.newtype_variant()
+    }
+}
@@ -0,0 +1,52 @@
+/*
+    Appellation: transparent <module>
+    Created At: 2026.10.19:15:16:27
+    Contrib: @FL03
+*/
+//! (de)serializes a [`Newtype`] as its inner value
+//!
+//! ```rust
+//! use contained_core::wrappers::TotalOrd;
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Record {
+//!     #[serde(with = "contained_core::serde::transparent")]
+//!     score: TotalOrd<f64>,
+//! }
+//! ```
+use crate::traits::Newtype;
+use core::fmt::Display;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// serializes the wrapper as its inner value
+pub fn serialize<W, S>(value: &W, serializer: S) -> Result<S::Ok, S::Error>
+where
+    W: Newtype,
+    W::Inner: Serialize,
+    S: Serializer,
+{
+    value.as_inner().serialize(serializer)
+}
+
+/// deserializes the wrapper from its inner value
+pub fn deserialize<'de, W, D>(deserializer: D) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    W::Inner::deserialize(deserializer).map(W::from_inner)
+}
+
+/// deserializes the inner value before passing it through the given, checked constructor
+pub fn deserialize_with<'de, W, D, F, E>(deserializer: D, f: F) -> Result<W, D::Error>
+where
+    W: Newtype,
+    W::Inner: Deserialize<'de>,
+    D: Deserializer<'de>,
+    F: FnOnce(W::Inner) -> Result<W, E>,
+    E: Display,
+{
+    let inner = W::Inner::deserialize(deserializer)?;
+    f(inner).map_err(de::Error::custom)
+}
@@ -0,0 +1,173 @@
+/*
+    Appellation: atomic <module>
+    Created At: 2026.10.19:13:41:30
+    Contrib: @FL03
+*/
+use core::sync::atomic::Ordering;
+
+/// The [`AtomicPrimitive`] trait is a sealed interface over the unsigned integers that have a
+/// native atomic counterpart on the target platform.
+pub trait AtomicPrimitive: Copy + Eq {
+    /// the atomic type used to store values of this type
+    type Atomic: Send + Sync;
+
+    private!();
+
+    /// returns a new atomic initialized with the given value
+    fn new_atomic(value: Self) -> Self::Atomic;
+    /// consumes the atomic to return the stored value
+    fn into_inner(atomic: Self::Atomic) -> Self;
+    /// loads the value stored in the atomic
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    /// stores the value into the atomic
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+    /// stores the value into the atomic, returning the previous value
+    fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+    /// stores `new` into the atomic if the current value is equal to `current`
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+    /// stores `new` into the atomic if the current value is equal to `current`; unlike
+    /// [`compare_exchange`](AtomicPrimitive::compare_exchange), this function may spuriously
+    /// fail
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+/// The [`AtomicRepr`] trait describes types with a lossless representation as one of the
+/// [`AtomicPrimitive`] integers, enabling them to be stored within an
+/// [`Atomic`](crate::wrappers::Atomic). Implementors must guarantee that
+/// `T::from_repr(value.into_repr())` is equivalent to `value` and that two values are equal
+/// if, and only if, their representations are equal.
+pub trait AtomicRepr: Copy {
+    /// the primitive used to represent the type
+    type Repr: AtomicPrimitive;
+
+    /// converts the value into its primitive representation
+    fn into_repr(self) -> Self::Repr;
+    /// restores a value from its primitive representation
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+macro_rules! impl_atomic_primitive {
+    ($($T:ident => $atomic:ident @ $width:literal),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicPrimitive for $T {
+                type Atomic = core::sync::atomic::$atomic;
+
+                seal!();
+
+                fn new_atomic(value: Self) -> Self::Atomic {
+                    core::sync::atomic::$atomic::new(value)
+                }
+
+                fn into_inner(atomic: Self::Atomic) -> Self {
+                    atomic.into_inner()
+                }
+
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order)
+                }
+
+                fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.swap(value, order)
+                }
+
+                fn compare_exchange(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange(current, new, success, failure)
+                }
+
+                fn compare_exchange_weak(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange_weak(current, new, success, failure)
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicRepr for $T {
+                type Repr = $T;
+
+                fn into_repr(self) -> Self::Repr {
+                    self
+                }
+
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_atomic_repr_signed {
+    ($($T:ident => $U:ident @ $width:literal),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicRepr for $T {
+                type Repr = $U;
+
+                fn into_repr(self) -> Self::Repr {
+                    self as $U
+                }
+
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr as $T
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_primitive! {
+    u8 => AtomicU8 @ "8",
+    u16 => AtomicU16 @ "16",
+    u32 => AtomicU32 @ "32",
+    u64 => AtomicU64 @ "64",
+    usize => AtomicUsize @ "ptr",
+}
+
+impl_atomic_repr_signed! {
+    i8 => u8 @ "8",
+    i16 => u16 @ "16",
+    i32 => u32 @ "32",
+    i64 => u64 @ "64",
+    isize => usize @ "ptr",
+}
+
+#[cfg(target_has_atomic = "8")]
+impl AtomicRepr for bool {
+    type Repr = u8;
+
+    fn into_repr(self) -> Self::Repr {
+        self as u8
+    }
+
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr != 0
+    }
+}
@@ -0,0 +1,38 @@
+/*
+    Appellation: float <module>
+    Created At: 2026.10.19:14:05:12
+    Contrib: @FL03
+*/
+use core::cmp::Ordering;
+
+/// The [`TotalFloat`] trait is a sealed interface over the primitive floating-point types,
+/// exposing the IEEE 754 `totalOrder` predicate along with the raw bits of a value. Two
+/// values are equal under the total ordering if, and only if, their bits are equal.
+pub trait TotalFloat: num_traits::float::FloatCore {
+    private!();
+
+    /// compares two values using the IEEE 754 `totalOrder` predicate
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    /// returns the raw bits of the value, widened to 64 bits
+    fn to_bits_u64(&self) -> u64;
+}
+
+macro_rules! impl_total_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl TotalFloat for $t {
+                seal!();
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+
+                fn to_bits_u64(&self) -> u64 {
+                    self.to_bits() as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_total_float! { f32, f64 }
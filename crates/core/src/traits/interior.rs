@@ -0,0 +1,43 @@
+/*
+    Appellation: interior <module>
+    Created At: 2026.10.19:12:31:09
+    Contrib: @FL03
+*/
+use crate::Result;
+
+/// The [`InteriorMut`] trait establishes a uniform interface for wrappers providing interior
+/// mutability, allowing their inner value to be accessed, and modified, through a shared
+/// reference. Failures to acquire access, such as a conflicting borrow or a poisoned lock, are
+/// surfaced as an [`Error`](crate::Error).
+pub trait InteriorMut<T> {
+    type Cont<U>;
+
+    /// apply the given function to an immutable reference to the inner value
+    fn with<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> R;
+    /// apply the given function to a mutable reference to the inner value
+    fn with_mut<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut T) -> R;
+    /// returns a new container holding the result of applying the function to the inner value
+    fn map<U, F>(&self, f: F) -> Result<Self::Cont<U>>
+    where
+        F: FnOnce(&T) -> U;
+    /// [`replace`](core::mem::replace) the inner value with the given, returning the previous
+    /// value
+    fn replace(&self, value: T) -> Result<T> {
+        self.with_mut(|inner| core::mem::replace(inner, value))
+    }
+    /// set the inner value, in-place
+    fn set(&self, value: T) -> Result<()> {
+        self.with_mut(|inner| *inner = value)
+    }
+    /// [`take`](core::mem::take) the inner value, leaving a default in its place
+    fn take(&self) -> Result<T>
+    where
+        T: Default,
+    {
+        self.with_mut(core::mem::take)
+    }
+}
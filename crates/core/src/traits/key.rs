@@ -0,0 +1,97 @@
+/*
+    Appellation: key <module>
+    Created At: 2026.10.19:14:48:20
+    Contrib: @FL03
+*/
+use core::cmp::{Ordering, Reverse};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// The [`KeyFn`] trait defines a projection from a value onto a key used to compare, order and
+/// hash the value within a [`ByKey`](crate::wrappers::ByKey) wrapper. Implementors are
+/// typically zero-sized markers, or the key type itself when the key is borrowed directly
+/// from the value.
+pub trait KeyFn<T: ?Sized> {
+    /// the key projected from the value
+    type Key<'a>: Eq + Ord + Hash
+    where
+        T: 'a;
+
+    /// returns the key of the given value
+    fn key(value: &T) -> Self::Key<'_>;
+}
+
+/// The [`Rev`] projection reverses the ordering of the key projected by `K`
+pub struct Rev<K>(PhantomData<K>);
+
+/// The [`CaseInsensitive`] projection compares, orders and hashes strings while ignoring the
+/// ASCII case of their characters
+pub struct CaseInsensitive;
+
+/// The key produced by the [`CaseInsensitive`] projection
+#[derive(Clone, Copy, Debug)]
+pub struct Caseless<'a>(pub &'a str);
+
+impl<T, K> KeyFn<T> for Rev<K>
+where
+    K: KeyFn<T>,
+    T: ?Sized,
+{
+    type Key<'a>
+        = Reverse<K::Key<'a>>
+    where
+        T: 'a;
+
+    fn key(value: &T) -> Self::Key<'_> {
+        Reverse(K::key(value))
+    }
+}
+
+impl<T> KeyFn<T> for CaseInsensitive
+where
+    T: AsRef<str> + ?Sized,
+{
+    type Key<'a>
+        = Caseless<'a>
+    where
+        T: 'a;
+
+    fn key(value: &T) -> Self::Key<'_> {
+        Caseless(value.as_ref())
+    }
+}
+
+impl Caseless<'_> {
+    /// returns an iterator over the lowercase bytes of the string
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.bytes().map(|b| b.to_ascii_lowercase())
+    }
+}
+
+impl PartialEq for Caseless<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+}
+
+impl Eq for Caseless<'_> {}
+
+impl PartialOrd for Caseless<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Caseless<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
+impl Hash for Caseless<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes().for_each(|b| state.write_u8(b));
+        // terminate the string so the hashes of adjacent values remain prefix-free
+        state.write_u8(0xff);
+    }
+}
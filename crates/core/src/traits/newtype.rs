@@ -0,0 +1,35 @@
+/*
+    Appellation: newtype <module>
+    Created At: 2026.10.19:15:10:44
+    Contrib: @FL03
+*/
+
+/// The [`Newtype`] trait describes a single-field wrapper in terms of its inner value,
+/// providing the uniform interface used by the serialization adapters within the `serde`
+/// module and the wrapper-keyed maps within the `collections` module. The trait is implemented
+/// by the `Wrapper` derive whenever a `serde` representation is requested.
+pub trait Newtype: Sized {
+    /// the type of the wrapped value
+    type Inner;
+    /// the name of the wrapper, used as the tag or struct name when serializing
+    const NAME: &'static str;
+    /// the name of the wrapped field, used when serializing the wrapper as a struct
+    const FIELD: &'static str = "value";
+
+    /// returns a new instance from the given inner value
+    fn from_inner(inner: Self::Inner) -> Self;
+    /// returns an immutable reference to the inner value
+    fn as_inner(&self) -> &Self::Inner;
+    /// consumes the wrapper to return the inner value
+    fn into_inner(self) -> Self::Inner;
+}
+
+/// The [`Transparent`] trait marks a [`Newtype`] sharing the memory layout of its inner value,
+/// allowing collections of either to be reinterpreted as the other without copying.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(transparent)]` over [`Inner`](Newtype::Inner), and every value
+/// of the inner type must be a valid instance of the wrapper; i.e.
+/// [`from_inner`](Newtype::from_inner) performs no validation.
+pub unsafe trait Transparent: Newtype {}
@@ -0,0 +1,245 @@
+/*
+    Appellation: dim <module>
+    Created At: 2026.10.19:13:02:41
+    Contrib: @FL03
+*/
+//! this module defines the type-level integers and dimensions used to describe the physical
+//! dimension of a [`Unit`](super::Unit) at compile time.
+use core::marker::PhantomData;
+
+/// The [`Integer`] trait is implemented by each of the type-level integers, ranging from
+/// [`N4`] to [`P4`], used as the exponents of a [`Dim`].
+pub trait Integer: Copy + Default + 'static {
+    /// the value of the integer
+    const VALUE: i8;
+
+    private!();
+}
+
+/// Type-level increment, i.e. `N + 1`
+pub trait Inc: Integer {
+    type Output: Integer;
+}
+
+/// Type-level decrement, i.e. `N - 1`
+pub trait Dec: Integer {
+    type Output: Integer;
+}
+
+/// Type-level negation, i.e. `-N`
+pub trait IntNeg: Integer {
+    type Output: Integer;
+}
+
+/// Type-level addition, i.e. `N + Rhs`
+pub trait IntAdd<Rhs: Integer>: Integer {
+    type Output: Integer;
+}
+
+/// Type-level subtraction, i.e. `N - Rhs`
+pub trait IntSub<Rhs: Integer>: Integer {
+    type Output: Integer;
+}
+
+/// The [`Dimension`] trait describes the exponents of the base quantities, length, mass and
+/// time, making up a physical dimension.
+pub trait Dimension: Copy + Default + 'static {
+    /// the exponent of length
+    type L: Integer;
+    /// the exponent of mass
+    type M: Integer;
+    /// the exponent of time
+    type T: Integer;
+}
+
+/// The [`Dim`] type encodes a physical dimension using the exponents of length (`L`), mass
+/// (`M`) and time (`T`); e.g. velocity is `Dim<P1, Z0, N1>`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Dim<L, M, T>(PhantomData<(L, M, T)>);
+
+/// the dimension resulting from the product of two dimensions
+pub type DimMul<A, B> = Dim<
+    <<A as Dimension>::L as IntAdd<<B as Dimension>::L>>::Output,
+    <<A as Dimension>::M as IntAdd<<B as Dimension>::M>>::Output,
+    <<A as Dimension>::T as IntAdd<<B as Dimension>::T>>::Output,
+>;
+
+/// the dimension resulting from the quotient of two dimensions
+pub type DimDiv<A, B> = Dim<
+    <<A as Dimension>::L as IntSub<<B as Dimension>::L>>::Output,
+    <<A as Dimension>::M as IntSub<<B as Dimension>::M>>::Output,
+    <<A as Dimension>::T as IntSub<<B as Dimension>::T>>::Output,
+>;
+
+/// the dimensionless dimension
+pub type Dimensionless = Dim<Z0, Z0, Z0>;
+/// the dimension of length
+pub type Length = Dim<P1, Z0, Z0>;
+/// the dimension of mass
+pub type Mass = Dim<Z0, P1, Z0>;
+/// the dimension of time
+pub type Time = Dim<Z0, Z0, P1>;
+/// the dimension of area
+pub type Area = Dim<P2, Z0, Z0>;
+/// the dimension of velocity
+pub type Velocity = Dim<P1, Z0, N1>;
+/// the dimension of acceleration
+pub type Acceleration = Dim<P1, Z0, N2>;
+/// the dimension of force
+pub type Force = Dim<P1, P1, N2>;
+
+impl<L, M, T> Dimension for Dim<L, M, T>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+{
+    type L = L;
+    type M = M;
+    type T = T;
+}
+
+macro_rules! integers {
+    ($($(#[doc = $doc:literal])* $name:ident = $value:literal),* $(,)?) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct $name;
+
+            impl Integer for $name {
+                const VALUE: i8 = $value;
+
+                seal!();
+            }
+        )*
+    };
+}
+
+macro_rules! succession {
+    ($($prev:ident => $next:ident),* $(,)?) => {
+        $(
+            impl Inc for $prev {
+                type Output = $next;
+            }
+
+            impl Dec for $next {
+                type Output = $prev;
+            }
+        )*
+    };
+}
+
+macro_rules! negation {
+    ($($a:ident <=> $b:ident),* $(,)?) => {
+        $(
+            impl IntNeg for $a {
+                type Output = $b;
+            }
+
+            impl IntNeg for $b {
+                type Output = $a;
+            }
+        )*
+    };
+}
+
+macro_rules! addition {
+    (@pos $($n:ident => $prev:ident),* $(,)?) => {
+        $(
+            impl<R> IntAdd<R> for $n
+            where
+                R: Integer,
+                $prev: IntAdd<R>,
+                <$prev as IntAdd<R>>::Output: Inc,
+            {
+                type Output = <<$prev as IntAdd<R>>::Output as Inc>::Output;
+            }
+        )*
+    };
+    (@neg $($n:ident => $next:ident),* $(,)?) => {
+        $(
+            impl<R> IntAdd<R> for $n
+            where
+                R: Integer,
+                $next: IntAdd<R>,
+                <$next as IntAdd<R>>::Output: Dec,
+            {
+                type Output = <<$next as IntAdd<R>>::Output as Dec>::Output;
+            }
+        )*
+    };
+}
+
+integers! {
+    #[doc = "the type-level integer `-4`"]
+    N4 = -4,
+    #[doc = "the type-level integer `-3`"]
+    N3 = -3,
+    #[doc = "the type-level integer `-2`"]
+    N2 = -2,
+    #[doc = "the type-level integer `-1`"]
+    N1 = -1,
+    #[doc = "the type-level integer `0`"]
+    Z0 = 0,
+    #[doc = "the type-level integer `1`"]
+    P1 = 1,
+    #[doc = "the type-level integer `2`"]
+    P2 = 2,
+    #[doc = "the type-level integer `3`"]
+    P3 = 3,
+    #[doc = "the type-level integer `4`"]
+    P4 = 4,
+}
+
+succession! {
+    N4 => N3,
+    N3 => N2,
+    N2 => N1,
+    N1 => Z0,
+    Z0 => P1,
+    P1 => P2,
+    P2 => P3,
+    P3 => P4,
+}
+
+negation! {
+    N4 <=> P4,
+    N3 <=> P3,
+    N2 <=> P2,
+    N1 <=> P1,
+}
+
+impl IntNeg for Z0 {
+    type Output = Z0;
+}
+
+impl<R> IntAdd<R> for Z0
+where
+    R: Integer,
+{
+    type Output = R;
+}
+
+addition! {
+    @pos
+    P1 => Z0,
+    P2 => P1,
+    P3 => P2,
+    P4 => P3,
+}
+
+addition! {
+    @neg
+    N1 => Z0,
+    N2 => N1,
+    N3 => N2,
+    N4 => N3,
+}
+
+impl<N, R> IntSub<R> for N
+where
+    N: IntAdd<<R as IntNeg>::Output>,
+    R: IntNeg,
+{
+    type Output = <N as IntAdd<<R as IntNeg>::Output>>::Output;
+}
@@ -0,0 +1,251 @@
+/*
+    Appellation: quantity <module>
+    Created At: 2026.10.19:13:24:52
+    Contrib: @FL03
+*/
+//! this module implements the [`Quantity`] wrapper, associating a value with a unit of
+//! measure checked at compile time.
+use super::{Per, Prod, Unit};
+use crate::traits::{Newtype, Transparent};
+#[cfg(feature = "macros")]
+use contained_macros::{binary_wrapper, unary_wrapper};
+use core::marker::PhantomData;
+use num_traits::AsPrimitive;
+use num_traits::float::FloatCore;
+
+/// The [`Quantity`] wrapper associates a value with a type-level [`Unit`]. Quantities may only
+/// be added to, subtracted from, or compared with quantities of the same unit, while their
+/// products and quotients yield quantities of the [`Prod`] and [`Per`] units, respectively.
+/// Their addition, subtraction, negation and scaling require the `macros` feature.
+///
+/// ```rust
+/// use contained_core::units::{Kilometer, Meter, Per, Quantity, Second};
+///
+/// let distance = Quantity::<f64, Kilometer>::new(1.5).convert::<Meter>();
+/// let speed: Quantity<f64, Per<Meter, Second>> = distance / Quantity::new(60.0);
+/// assert_eq!(speed.to_string(), "25 m/s");
+/// ```
+#[repr(transparent)]
+pub struct Quantity<T, U> {
+    value: T,
+    unit: PhantomData<U>,
+}
+
+impl<T, U> Quantity<T, U> {
+    /// returns a new quantity with the given value
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            unit: PhantomData,
+        }
+    }
+    /// returns an immutable reference to the value
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+    /// returns a mutable reference to the value
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+    /// consumes the quantity to return the value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+    /// apply the function to the value, preserving the unit
+    pub fn map<V, F>(self, f: F) -> Quantity<V, U>
+    where
+        F: FnOnce(T) -> V,
+    {
+        Quantity::new(f(self.value))
+    }
+    /// [`replace`](core::mem::replace) the value, returning the previous value
+    pub const fn replace(&mut self, value: T) -> T {
+        core::mem::replace(&mut self.value, value)
+    }
+    /// set the value of the quantity
+    pub fn set(&mut self, value: T) -> &mut Self {
+        self.value = value;
+        self
+    }
+    /// converts the quantity into another unit of the same dimension
+    pub fn convert<V>(self) -> Quantity<T, V>
+    where
+        T: FloatCore + 'static,
+        U: Unit,
+        V: Unit<Dim = U::Dim>,
+        f64: AsPrimitive<T>,
+    {
+        let ratio: T = (U::SCALE / V::SCALE).as_();
+        Quantity::new(self.value * ratio)
+    }
+}
+
+impl<T, U> Clone for Quantity<T, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T, U> Copy for Quantity<T, U> where T: Copy {}
+
+impl<T, U> Default for Quantity<T, U>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, U> core::fmt::Debug for Quantity<T, U>
+where
+    T: core::fmt::Debug,
+    U: Unit,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.value, f)?;
+        f.write_str(" ")?;
+        U::fmt_symbol(f)
+    }
+}
+
+impl<T, U> core::fmt::Display for Quantity<T, U>
+where
+    T: core::fmt::Display,
+    U: Unit,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.value, f)?;
+        f.write_str(" ")?;
+        U::fmt_symbol(f)
+    }
+}
+
+impl<T, U> Newtype for Quantity<T, U> {
+    type Inner = T;
+
+    const NAME: &'static str = "Quantity";
+
+    fn from_inner(inner: T) -> Self {
+        Self::new(inner)
+    }
+
+    fn as_inner(&self) -> &T {
+        &self.value
+    }
+
+    fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+unsafe impl<T, U> Transparent for Quantity<T, U> {}
+
+impl<T, U> AsRef<T> for Quantity<T, U> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T, U> AsMut<T> for Quantity<T, U> {
+    fn as_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T, U> PartialEq for Quantity<T, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T, U> Eq for Quantity<T, U> where T: Eq {}
+
+impl<T, U> PartialOrd for Quantity<T, U>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T, U> Ord for Quantity<T, U>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T, U> core::hash::Hash for Quantity<T, U>
+where
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<A, B, U, V> core::ops::Mul<Quantity<B, V>> for Quantity<A, U>
+where
+    A: core::ops::Mul<B>,
+    Prod<U, V>: Unit,
+{
+    type Output = Quantity<A::Output, Prod<U, V>>;
+
+    fn mul(self, rhs: Quantity<B, V>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+impl<A, B, U, V> core::ops::Div<Quantity<B, V>> for Quantity<A, U>
+where
+    A: core::ops::Div<B>,
+    Per<U, V>: Unit,
+{
+    type Output = Quantity<A::Output, Per<U, V>>;
+
+    fn div(self, rhs: Quantity<B, V>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+#[cfg(feature = "macros")]
+impl<T, U> num_traits::Zero for Quantity<T, U>
+where
+    T: num_traits::Zero,
+{
+    fn zero() -> Self {
+        Quantity::new(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+#[cfg(feature = "macros")]
+binary_wrapper! {
+    impl Quantity<_, U>.value {
+        Add.add,
+        Sub.sub,
+        Mul.mul(scalar f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize),
+        Div.div(rhs scalar f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize),
+    }
+}
+
+#[cfg(feature = "macros")]
+unary_wrapper! {
+    impl Quantity<_, U>.value {
+        Neg.neg,
+    }
+}
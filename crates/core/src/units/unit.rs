@@ -0,0 +1,114 @@
+/*
+    Appellation: unit <module>
+    Created At: 2026.10.19:13:10:27
+    Contrib: @FL03
+*/
+//! this module defines the [`Unit`] trait along with a set of common units and the
+//! [`Prod`] and [`Per`] combinators used to derive new units from existing ones.
+use super::dim::*;
+use core::marker::PhantomData;
+
+/// The [`Unit`] trait describes a unit of measure using its [`Dimension`], its scale relative
+/// to the coherent unit of that dimension, and its symbol. Units sharing a dimension may be
+/// converted between one another.
+pub trait Unit: Copy + Default + 'static {
+    /// the physical dimension of the unit
+    type Dim: Dimension;
+    /// the factor converting a value in this unit into the coherent unit of its dimension;
+    /// e.g. `1000.0` for kilometers
+    const SCALE: f64;
+
+    /// write the symbol of the unit into the given formatter
+    fn fmt_symbol(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+/// The product of two units, e.g. `Prod<Meter, Meter>` for square meters
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Prod<A, B>(PhantomData<(A, B)>);
+
+/// The quotient of two units, e.g. `Per<Meter, Second>` for meters per second
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Per<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> Unit for Prod<A, B>
+where
+    A: Unit,
+    B: Unit,
+    <A::Dim as Dimension>::L: IntAdd<<B::Dim as Dimension>::L>,
+    <A::Dim as Dimension>::M: IntAdd<<B::Dim as Dimension>::M>,
+    <A::Dim as Dimension>::T: IntAdd<<B::Dim as Dimension>::T>,
+{
+    type Dim = DimMul<A::Dim, B::Dim>;
+
+    const SCALE: f64 = A::SCALE * B::SCALE;
+
+    fn fmt_symbol(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        A::fmt_symbol(f)?;
+        f.write_str("·")?;
+        B::fmt_symbol(f)
+    }
+}
+
+impl<A, B> Unit for Per<A, B>
+where
+    A: Unit,
+    B: Unit,
+    <A::Dim as Dimension>::L: IntSub<<B::Dim as Dimension>::L>,
+    <A::Dim as Dimension>::M: IntSub<<B::Dim as Dimension>::M>,
+    <A::Dim as Dimension>::T: IntSub<<B::Dim as Dimension>::T>,
+{
+    type Dim = DimDiv<A::Dim, B::Dim>;
+
+    const SCALE: f64 = A::SCALE / B::SCALE;
+
+    fn fmt_symbol(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        A::fmt_symbol(f)?;
+        f.write_str("/")?;
+        B::fmt_symbol(f)
+    }
+}
+
+macro_rules! units {
+    ($($(#[doc = $doc:literal])* $name:ident: $dim:ty = $scale:literal, $symbol:literal);* $(;)?) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct $name;
+
+            impl Unit for $name {
+                type Dim = $dim;
+
+                const SCALE: f64 = $scale;
+
+                fn fmt_symbol(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str($symbol)
+                }
+            }
+        )*
+    };
+}
+
+units! {
+    #[doc = "the meter, the coherent unit of length"]
+    Meter: Length = 1.0, "m";
+    #[doc = "the millimeter, a thousandth of a meter"]
+    Millimeter: Length = 0.001, "mm";
+    #[doc = "the centimeter, a hundredth of a meter"]
+    Centimeter: Length = 0.01, "cm";
+    #[doc = "the kilometer, a thousand meters"]
+    Kilometer: Length = 1000.0, "km";
+    #[doc = "the gram, a thousandth of a kilogram"]
+    Gram: Mass = 0.001, "g";
+    #[doc = "the kilogram, the coherent unit of mass"]
+    Kilogram: Mass = 1.0, "kg";
+    #[doc = "the second, the coherent unit of time"]
+    Second: Time = 1.0, "s";
+    #[doc = "the millisecond, a thousandth of a second"]
+    Millisecond: Time = 0.001, "ms";
+    #[doc = "the minute, sixty seconds"]
+    Minute: Time = 60.0, "min";
+    #[doc = "the hour, sixty minutes"]
+    Hour: Time = 3600.0, "h";
+    #[doc = "the newton, the coherent unit of force"]
+    Newton: Force = 1.0, "N";
+}
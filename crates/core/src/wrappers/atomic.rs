@@ -0,0 +1,145 @@
+/*
+    Appellation: atomic <module>
+    Created At: 2026.10.19:14:02:55
+    Contrib: @FL03
+*/
+//! this module implements the [`Atomic`] wrapper, enabling any type with a lossless integer
+//! representation to be shared and updated atomically.
+use crate::traits::{AtomicPrimitive, AtomicRepr};
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+/// a type alias for the atomic used to store the representation of `W`
+type AtomicOf<W> = <<W as AtomicRepr>::Repr as AtomicPrimitive>::Atomic;
+
+/// The [`Atomic`] wrapper stores a value of type `W` within the atomic integer matching its
+/// [`AtomicRepr`], converting to and from the representation on each access. This allows
+/// enums and integer-backed newtypes to be used atomically without converting by hand.
+pub struct Atomic<W>
+where
+    W: AtomicRepr,
+{
+    inner: AtomicOf<W>,
+    _marker: PhantomData<W>,
+}
+
+impl<W> Atomic<W>
+where
+    W: AtomicRepr,
+{
+    /// returns a new instance initialized with the given value
+    pub fn new(value: W) -> Self {
+        Self {
+            inner: W::Repr::new_atomic(value.into_repr()),
+            _marker: PhantomData,
+        }
+    }
+    /// returns a reference to the underlying atomic
+    pub const fn as_atomic(&self) -> &AtomicOf<W> {
+        &self.inner
+    }
+    /// consumes the current instance to return the stored value
+    #[inline]
+    pub fn into_inner(self) -> W {
+        W::from_repr(W::Repr::into_inner(self.inner))
+    }
+    /// loads the stored value
+    pub fn load(&self, order: Ordering) -> W {
+        W::from_repr(W::Repr::load(&self.inner, order))
+    }
+    /// stores the given value
+    pub fn store(&self, value: W, order: Ordering) {
+        W::Repr::store(&self.inner, value.into_repr(), order)
+    }
+    /// stores the given value, returning the previous one
+    pub fn swap(&self, value: W, order: Ordering) -> W {
+        W::from_repr(W::Repr::swap(&self.inner, value.into_repr(), order))
+    }
+    /// stores `new` if the current value is equal to `current`; the result contains the
+    /// previous value and indicates whether the exchange succeeded
+    pub fn compare_exchange(
+        &self,
+        current: W,
+        new: W,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<W, W> {
+        W::Repr::compare_exchange(
+            &self.inner,
+            current.into_repr(),
+            new.into_repr(),
+            success,
+            failure,
+        )
+        .map(W::from_repr)
+        .map_err(W::from_repr)
+    }
+    /// stores `new` if the current value is equal to `current`; unlike
+    /// [`compare_exchange`](Self::compare_exchange), this method may spuriously fail
+    pub fn compare_exchange_weak(
+        &self,
+        current: W,
+        new: W,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<W, W> {
+        W::Repr::compare_exchange_weak(
+            &self.inner,
+            current.into_repr(),
+            new.into_repr(),
+            success,
+            failure,
+        )
+        .map(W::from_repr)
+        .map_err(W::from_repr)
+    }
+    /// repeatedly applies the function to the current value until it either returns `None`
+    /// or the result is successfully stored; returns `Ok` with the previous value on success
+    pub fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<W, W>
+    where
+        F: FnMut(W) -> Option<W>,
+    {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = f(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                Ok(value) => return Ok(value),
+                Err(value) => prev = value,
+            }
+        }
+        Err(prev)
+    }
+}
+
+impl<W> Default for Atomic<W>
+where
+    W: AtomicRepr + Default,
+{
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+impl<W> From<W> for Atomic<W>
+where
+    W: AtomicRepr,
+{
+    fn from(value: W) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<W> core::fmt::Debug for Atomic<W>
+where
+    W: AtomicRepr + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Atomic")
+            .field(&self.load(Ordering::Relaxed))
+            .finish()
+    }
+}
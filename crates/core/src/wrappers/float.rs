@@ -0,0 +1,326 @@
+/*
+    Appellation: float <module>
+    Created At: 2026.10.19:14:12:36
+    Contrib: @FL03
+*/
+//! this module implements the [`TotalOrd`] and [`NotNan`] wrappers, providing floating-point
+//! values with the [`Eq`], [`Ord`] and [`Hash`](core::hash::Hash) implementations required to
+//! use them as keys within maps and sets.
+use crate::error::{Error, Result};
+use crate::traits::{Newtype, TotalFloat, Transparent};
+#[cfg(feature = "macros")]
+use contained_macros::{binary_wrapper, unary_wrapper};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// The [`TotalOrd`] wrapper compares, orders and hashes floating-point values using the
+/// IEEE 754 `totalOrder` predicate; i.e. `-0.0 < +0.0` and every `NaN` is comparable, with
+/// positive `NaN`s ordered after positive infinity. Its arithmetic requires the `macros`
+/// feature.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct TotalOrd<T>(pub T);
+
+/// The [`NotNan`] wrapper guarantees its inner value is never `NaN`. Like the [`TotalOrd`]
+/// wrapper, its comparisons and hash are consistent with the IEEE 754 `totalOrder` predicate.
+///
+/// Arithmetic between [`NotNan`] values, or with raw floats, **panics** whenever the result is
+/// `NaN`, e.g. `∞ - ∞` or `0 / 0`; apart from negation, it requires the `macros` feature.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct NotNan<T>(T);
+
+impl<T> TotalOrd<T> {
+    /// returns a new instance with the given value
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+    /// returns a mutable reference to the inner value
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+    /// consumes the current instance to return the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> NotNan<T>
+where
+    T: TotalFloat,
+{
+    /// returns a new instance with the given value, or an [`Error::NaN`] if it is `NaN`
+    pub fn try_new(value: T) -> Result<Self> {
+        if value.is_nan() {
+            return Err(Error::NaN);
+        }
+        Ok(Self(value))
+    }
+    /// returns a new instance with the given value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `NaN`
+    #[inline]
+    pub fn new(value: T) -> Self {
+        match Self::try_new(value) {
+            Ok(value) => value,
+            Err(_) => panic!("`NotNan` cannot hold a NaN value"),
+        }
+    }
+}
+
+impl<T> NotNan<T> {
+    /// returns a new instance with the given value without checking whether it is `NaN`
+    ///
+    /// # Safety
+    ///
+    /// The value must not be `NaN`; otherwise, the comparisons of the wrapper are unspecified.
+    pub const unsafe fn new_unchecked(value: T) -> Self {
+        Self(value)
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+    /// consumes the current instance to return the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> PartialEq for TotalOrd<T>
+where
+    T: TotalFloat,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl<T> Eq for TotalOrd<T> where T: TotalFloat {}
+
+impl<T> PartialOrd for TotalOrd<T>
+where
+    T: TotalFloat,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TotalOrd<T>
+where
+    T: TotalFloat,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T> Hash for TotalOrd<T>
+where
+    T: TotalFloat,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits_u64().hash(state)
+    }
+}
+
+impl<T> Newtype for TotalOrd<T> {
+    type Inner = T;
+
+    const NAME: &'static str = "TotalOrd";
+
+    fn from_inner(inner: T) -> Self {
+        Self(inner)
+    }
+
+    fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+unsafe impl<T> Transparent for TotalOrd<T> {}
+
+impl<T> From<T> for TotalOrd<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::fmt::Display for TotalOrd<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "macros")]
+binary_wrapper! {
+    impl TotalOrd {
+        Add.add,
+        Sub.sub,
+        Mul.mul,
+        Div.div,
+        Rem.rem,
+    }
+}
+
+#[cfg(feature = "macros")]
+unary_wrapper! {
+    impl TotalOrd {
+        Neg.neg,
+    }
+}
+
+impl<T> PartialEq for NotNan<T>
+where
+    T: TotalFloat,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl<T> Eq for NotNan<T> where T: TotalFloat {}
+
+impl<T> PartialOrd for NotNan<T>
+where
+    T: TotalFloat,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for NotNan<T>
+where
+    T: TotalFloat,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T> Hash for NotNan<T>
+where
+    T: TotalFloat,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits_u64().hash(state)
+    }
+}
+
+impl<T> TryFrom<TotalOrd<T>> for NotNan<T>
+where
+    T: TotalFloat,
+{
+    type Error = Error;
+
+    fn try_from(value: TotalOrd<T>) -> Result<Self> {
+        Self::try_new(value.0)
+    }
+}
+
+impl<T> From<NotNan<T>> for TotalOrd<T> {
+    fn from(value: NotNan<T>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl<T> AsRef<T> for NotNan<T> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> core::ops::Deref for NotNan<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T> core::fmt::Display for NotNan<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "macros")]
+binary_wrapper! {
+    impl NotNan with NotNan::new where _: TotalFloat {
+        Add.add,
+        Sub.sub,
+        Mul.mul,
+        Div.div,
+        Rem.rem,
+        Add.add(scalar f32, f64),
+        Sub.sub(scalar f32, f64),
+        Mul.mul(scalar f32, f64),
+        Div.div(scalar f32, f64),
+        Rem.rem(scalar f32, f64),
+    }
+}
+
+// implemented by hand, since the `Neg` supertrait of [`TotalFloat`] conflicts with the
+// `Output` of the generic implementation and leaves it ambiguous
+impl<T> core::ops::Neg for NotNan<T>
+where
+    T: TotalFloat,
+{
+    type Output = NotNan<T>;
+
+    fn neg(self) -> Self::Output {
+        NotNan(-self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for NotNan<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for NotNan<T>
+where
+    T: TotalFloat + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Self::try_new(value).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,257 @@
+/*
+    Appellation: history <module>
+    Created At: 2026.10.19:09:12:41
+    Contrib: @FL03
+*/
+//! this module implements the [`History`] wrapper, a container that records snapshots of its
+//! inner value to support undo / redo operations.
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// The [`EvictionPolicy`] determines how a [`History`] makes room for new snapshots once it
+/// has reached its capacity.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EvictionPolicy {
+    /// discard the oldest snapshot to make room for the new one
+    #[default]
+    DropOldest,
+    /// discard the most recent snapshot, preserving the oldest entries in the history
+    DropNewest,
+    /// keep the existing snapshots and discard the incoming one
+    Skip,
+}
+
+/// The [`History`] wrapper records a snapshot of the previous value each time the inner value
+/// is modified, enabling the changes to be undone and redone. The number of snapshots stored
+/// is bounded by a configurable capacity, with the [`EvictionPolicy`] determining which
+/// snapshot is dropped once the history is full.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct History<T> {
+    value: T,
+    past: VecDeque<T>,
+    future: Vec<T>,
+    capacity: usize,
+    policy: EvictionPolicy,
+}
+
+/// A guard providing mutable access to the value of a [`History`]; a snapshot of the value, as
+/// it was when the guard was created, is committed to the history once the guard is dropped.
+pub struct HistoryGuard<'a, T> {
+    history: &'a mut History<T>,
+    snapshot: Option<T>,
+}
+
+impl<T> History<T> {
+    /// the default number of snapshots retained by a [`History`]
+    pub const DEFAULT_CAPACITY: usize = 64;
+    /// returns a new instance with the given value and the default capacity
+    pub const fn new(value: T) -> Self {
+        Self::with_capacity(value, Self::DEFAULT_CAPACITY)
+    }
+    /// returns a new instance with the given value that stores, at most, `capacity` snapshots
+    pub const fn with_capacity(value: T, capacity: usize) -> Self {
+        Self {
+            value,
+            past: VecDeque::new(),
+            future: Vec::new(),
+            capacity,
+            policy: EvictionPolicy::DropOldest,
+        }
+    }
+    /// consumes the current instance to create another with the given eviction policy
+    pub fn with_policy(self, policy: EvictionPolicy) -> Self {
+        Self { policy, ..self }
+    }
+    /// returns the maximum number of snapshots retained by the history
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// returns a copy of the current eviction policy
+    pub const fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+    /// returns a guard providing mutable access to the inner value; a snapshot of the
+    /// current value is recorded once the guard is dropped
+    pub fn get_mut(&mut self) -> HistoryGuard<'_, T>
+    where
+        T: Clone,
+    {
+        HistoryGuard {
+            snapshot: Some(self.value.clone()),
+            history: self,
+        }
+    }
+    /// consumes the current instance to return the inner value, discarding the history
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+    /// returns the number of snapshots that can be undone
+    pub fn history_len(&self) -> usize {
+        self.past.len()
+    }
+    /// returns the number of snapshots that can be redone
+    pub const fn redo_len(&self) -> usize {
+        self.future.len()
+    }
+    /// returns true if there is at least one snapshot to undo
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+    /// returns true if there is at least one snapshot to redo
+    pub const fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+    /// discard all of the recorded snapshots, leaving the current value untouched
+    pub fn clear_history(&mut self) {
+        self.past.clear();
+        self.future.clear();
+    }
+    /// update the capacity of the history, evicting snapshots according to the current policy
+    /// when the new capacity is smaller than the number of recorded snapshots
+    pub fn set_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = capacity;
+        while self.past.len() > capacity {
+            self.evict();
+        }
+        self
+    }
+    /// update the eviction policy of the history
+    pub fn set_policy(&mut self, policy: EvictionPolicy) -> &mut Self {
+        self.policy = policy;
+        self
+    }
+    /// set the inner value, recording the previous value as a snapshot
+    #[inline]
+    pub fn set(&mut self, value: T) -> &mut Self {
+        let prev = core::mem::replace(&mut self.value, value);
+        self.commit(prev);
+        self
+    }
+    /// [`replace`](core::mem::replace) the inner value with the given, returning the previous
+    /// value after recording a snapshot of it
+    pub fn replace(&mut self, value: T) -> T
+    where
+        T: Clone,
+    {
+        let prev = core::mem::replace(&mut self.value, value);
+        self.commit(prev.clone());
+        prev
+    }
+    /// record a snapshot of the current value without modifying it
+    pub fn checkpoint(&mut self) -> &mut Self
+    where
+        T: Clone,
+    {
+        self.commit(self.value.clone());
+        self
+    }
+    /// restores the most recent snapshot, returning true if a snapshot was available
+    pub fn undo(&mut self) -> bool {
+        match self.past.pop_back() {
+            Some(prev) => {
+                let curr = core::mem::replace(&mut self.value, prev);
+                self.future.push(curr);
+                true
+            }
+            None => false,
+        }
+    }
+    /// re-applies the most recently undone snapshot, returning true if one was available
+    pub fn redo(&mut self) -> bool {
+        match self.future.pop() {
+            Some(next) => {
+                let curr = core::mem::replace(&mut self.value, next);
+                self.past.push_back(curr);
+                true
+            }
+            None => false,
+        }
+    }
+    /// returns an iterator over the recorded snapshots, from the oldest to the most recent
+    pub fn snapshots(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.past.iter()
+    }
+    /// record the given snapshot, invalidating any redo entries
+    fn commit(&mut self, snapshot: T) {
+        self.future.clear();
+        if self.capacity == 0 {
+            return;
+        }
+        if self.past.len() >= self.capacity {
+            if let EvictionPolicy::Skip = self.policy {
+                return;
+            }
+            self.evict();
+        }
+        self.past.push_back(snapshot);
+    }
+    /// remove a single snapshot according to the current eviction policy
+    fn evict(&mut self) {
+        match self.policy {
+            EvictionPolicy::DropNewest => self.past.pop_back(),
+            _ => self.past.pop_front(),
+        };
+    }
+}
+
+impl<T> Default for History<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for History<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> AsRef<T> for History<T> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> core::borrow::Borrow<T> for History<T> {
+    fn borrow(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> core::ops::Deref for History<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T> core::ops::Deref for HistoryGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.history.value
+    }
+}
+
+impl<T> core::ops::DerefMut for HistoryGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.history.value
+    }
+}
+
+impl<T> Drop for HistoryGuard<'_, T> {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            self.history.commit(snapshot);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+/*
+    Appellation: key <module>
+    Created At: 2026.10.19:14:56:03
+    Contrib: @FL03
+*/
+//! this module implements the [`ByKey`] wrapper, comparing, ordering and hashing its inner
+//! value using a projected key.
+use crate::traits::{KeyFn, Newtype, Transparent};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// The [`ByKey`] wrapper implements [`Eq`], [`Ord`] and [`Hash`] using the key projected from
+/// its inner value by `K`, ignoring the remainder of the value.
+///
+/// Whenever the projection borrows the key directly, i.e. `K::Key<'a> = &'a K`, the wrapper
+/// also implements [`Borrow<K>`](core::borrow::Borrow), allowing sets and maps of wrapped
+/// values to be queried by key alone.
+///
+/// ```rust
+/// use contained_core::traits::KeyFn;
+/// use contained_core::wrappers::ByKey;
+/// use std::collections::HashSet;
+///
+/// #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// pub struct Id(u32);
+///
+/// pub struct User {
+///     pub id: Id,
+///     pub name: &'static str,
+/// }
+///
+/// impl KeyFn<User> for Id {
+///     type Key<'a> = &'a Id where User: 'a;
+///
+///     fn key(user: &User) -> &Id {
+///         &user.id
+///     }
+/// }
+///
+/// let mut users = HashSet::new();
+/// users.insert(ByKey::<User, Id>::new(User { id: Id(1), name: "alice" }));
+/// assert!(!users.insert(ByKey::new(User { id: Id(1), name: "bob" })));
+/// assert_eq!(users.get(&Id(1)).map(|u| u.name), Some("alice"));
+/// ```
+#[repr(transparent)]
+pub struct ByKey<T, K> {
+    value: T,
+    key: PhantomData<fn() -> K>,
+}
+
+impl<T, K> ByKey<T, K> {
+    /// returns a new instance with the given value
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            key: PhantomData,
+        }
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+    /// consumes the current instance to return the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+    /// returns the key projected from the inner value
+    pub fn key(&self) -> K::Key<'_>
+    where
+        K: KeyFn<T>,
+    {
+        K::key(&self.value)
+    }
+    /// consumes the current instance to create another using a different projection
+    pub fn rekey<J>(self) -> ByKey<T, J> {
+        ByKey::new(self.value)
+    }
+}
+
+impl<T, K> Clone for ByKey<T, K>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T, K> Copy for ByKey<T, K> where T: Copy {}
+
+impl<T, K> Default for ByKey<T, K>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, K> core::fmt::Debug for ByKey<T, K>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ByKey").field(&self.value).finish()
+    }
+}
+
+impl<T, K> core::fmt::Display for ByKey<T, K>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T, K> Newtype for ByKey<T, K> {
+    type Inner = T;
+
+    const NAME: &'static str = "ByKey";
+
+    fn from_inner(inner: T) -> Self {
+        Self::new(inner)
+    }
+
+    fn as_inner(&self) -> &T {
+        &self.value
+    }
+
+    fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+unsafe impl<T, K> Transparent for ByKey<T, K> {}
+
+impl<T, K> From<T> for ByKey<T, K> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, K> AsRef<T> for ByKey<T, K> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T, K> core::ops::Deref for ByKey<T, K> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T, K> PartialEq for ByKey<T, K>
+where
+    K: KeyFn<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T, K> Eq for ByKey<T, K> where K: KeyFn<T> {}
+
+impl<T, K> PartialOrd for ByKey<T, K>
+where
+    K: KeyFn<T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, K> Ord for ByKey<T, K>
+where
+    K: KeyFn<T>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<T, K> Hash for ByKey<T, K>
+where
+    K: KeyFn<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+/// the [`Eq`], [`Ord`] and [`Hash`] implementations of `&K` forward to those of `K`, making
+/// the borrow consistent with the implementations of the wrapper
+impl<T, K> core::borrow::Borrow<K> for ByKey<T, K>
+where
+    T: 'static,
+    K: for<'a> KeyFn<T, Key<'a> = &'a K>,
+{
+    fn borrow(&self) -> &K {
+        K::key(&self.value)
+    }
+}
@@ -0,0 +1,292 @@
+/*
+    Appellation: lazy <module>
+    Created At: 2026.10.19:11:20:52
+    Contrib: @FL03
+*/
+//! this module implements the [`Lazy`] wrapper, whose inner value is computed on first access,
+//! along with its thread-safe counterpart, [`SyncLazy`], when the `std` feature is enabled.
+use crate::traits::{Get, Wrapper, WrapperMut};
+use core::cell::{Cell, OnceCell};
+
+/// The [`Lazy`] wrapper computes its inner value using the given initializer the first time it
+/// is accessed. The implementation relies on a single-threaded cell, making it suitable for
+/// `no_std` environments; see [`SyncLazy`] for a thread-safe alternative.
+pub struct Lazy<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: Cell<Option<F>>,
+}
+
+impl<T, F> Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// returns a new instance that will compute its value using the given initializer
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init: Cell::new(Some(init)),
+        }
+    }
+    /// returns a reference to the inner value, computing it if necessary
+    pub fn get(&self) -> &T {
+        self.cell.get_or_init(|| match self.init.take() {
+            Some(init) => init(),
+            None => panic!("the initializer of the `Lazy` instance previously panicked"),
+        })
+    }
+    /// returns a mutable reference to the inner value, computing it if necessary
+    pub fn get_mut(&mut self) -> &mut T {
+        Lazy::get(self);
+        match self.cell.get_mut() {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+    /// consumes the current instance to return the inner value, computing it if necessary
+    #[inline]
+    pub fn into_inner(self) -> T {
+        Lazy::get(&self);
+        match self.cell.into_inner() {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, F> Lazy<T, F> {
+    /// returns true if the inner value has already been computed
+    pub fn is_init(&self) -> bool {
+        self.cell.get().is_some()
+    }
+    /// returns a reference to the inner value, if it has already been computed
+    pub fn peek(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+impl<T> Default for Lazy<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+impl<T, F> core::fmt::Debug for Lazy<T, F>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.peek() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<uninit>)"),
+        }
+    }
+}
+
+impl<T, F> core::ops::Deref for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        Lazy::get(self)
+    }
+}
+
+impl<T, F> core::ops::DerefMut for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Lazy::get_mut(self)
+    }
+}
+
+impl<T, F> Get<T> for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    fn get(&self) -> &T {
+        Lazy::get(self)
+    }
+}
+
+impl<T, F> Wrapper<T> for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    type Cont<U> = U;
+
+    fn get(&self) -> &T {
+        Lazy::get(self)
+    }
+
+    fn view(&self) -> Self::Cont<&T> {
+        Lazy::get(self)
+    }
+}
+
+impl<T, F> WrapperMut<T> for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    fn get_mut(&mut self) -> &mut T {
+        Lazy::get_mut(self)
+    }
+
+    fn view_mut(&mut self) -> Self::Cont<&mut T> {
+        Lazy::get_mut(self)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::sync::SyncLazy;
+
+#[cfg(feature = "std")]
+mod sync {
+    use crate::traits::{Get, Wrapper, WrapperMut};
+    use std::sync::{Mutex, OnceLock, PoisonError};
+
+    /// The [`SyncLazy`] wrapper is a thread-safe variant of the [`Lazy`](super::Lazy) wrapper,
+    /// guaranteeing that the initializer runs at most once, even when accessed concurrently.
+    pub struct SyncLazy<T, F = fn() -> T> {
+        cell: OnceLock<T>,
+        init: Mutex<Option<F>>,
+    }
+
+    impl<T, F> SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        /// returns a new instance that will compute its value using the given initializer
+        pub const fn new(init: F) -> Self {
+            Self {
+                cell: OnceLock::new(),
+                init: Mutex::new(Some(init)),
+            }
+        }
+        /// returns a reference to the inner value, computing it if necessary
+        pub fn get(&self) -> &T {
+            self.cell.get_or_init(|| {
+                let init = self
+                    .init
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .take();
+                match init {
+                    Some(init) => init(),
+                    None => panic!("the initializer of the `SyncLazy` instance previously panicked"),
+                }
+            })
+        }
+        /// returns a mutable reference to the inner value, computing it if necessary
+        pub fn get_mut(&mut self) -> &mut T {
+            SyncLazy::get(self);
+            match self.cell.get_mut() {
+                Some(value) => value,
+                None => unreachable!(),
+            }
+        }
+        /// consumes the current instance to return the inner value, computing it if necessary
+        #[inline]
+        pub fn into_inner(self) -> T {
+            SyncLazy::get(&self);
+            match self.cell.into_inner() {
+                Some(value) => value,
+                None => unreachable!(),
+            }
+        }
+    }
+
+    impl<T, F> SyncLazy<T, F> {
+        /// returns true if the inner value has already been computed
+        pub fn is_init(&self) -> bool {
+            self.cell.get().is_some()
+        }
+        /// returns a reference to the inner value, if it has already been computed
+        pub fn peek(&self) -> Option<&T> {
+            self.cell.get()
+        }
+    }
+
+    impl<T> Default for SyncLazy<T>
+    where
+        T: Default,
+    {
+        fn default() -> Self {
+            Self::new(T::default)
+        }
+    }
+
+    impl<T, F> core::fmt::Debug for SyncLazy<T, F>
+    where
+        T: core::fmt::Debug,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.peek() {
+                Some(value) => f.debug_tuple("SyncLazy").field(value).finish(),
+                None => f.write_str("SyncLazy(<uninit>)"),
+            }
+        }
+    }
+
+    impl<T, F> core::ops::Deref for SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            SyncLazy::get(self)
+        }
+    }
+
+    impl<T, F> core::ops::DerefMut for SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            SyncLazy::get_mut(self)
+        }
+    }
+
+    impl<T, F> Get<T> for SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        fn get(&self) -> &T {
+            SyncLazy::get(self)
+        }
+    }
+
+    impl<T, F> Wrapper<T> for SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        type Cont<U> = U;
+
+        fn get(&self) -> &T {
+            SyncLazy::get(self)
+        }
+
+        fn view(&self) -> Self::Cont<&T> {
+            SyncLazy::get(self)
+        }
+    }
+
+    impl<T, F> WrapperMut<T> for SyncLazy<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        fn get_mut(&mut self) -> &mut T {
+            SyncLazy::get_mut(self)
+        }
+
+        fn view_mut(&mut self) -> Self::Cont<&mut T> {
+            SyncLazy::get_mut(self)
+        }
+    }
+}
@@ -0,0 +1,307 @@
+/*
+    Appellation: memo <module>
+    Created At: 2026.10.19:11:48:05
+    Contrib: @FL03
+*/
+//! this module implements the [`Memo`] wrapper, which lazily computes its inner value from a
+//! dependency key and recomputes it whenever the key changes, as well as its thread-safe
+//! counterpart, [`SyncMemo`], when the `std` feature is enabled.
+use crate::traits::{Get, Wrapper, WrapperMut};
+use core::cell::OnceCell;
+
+/// The [`Memo`] wrapper caches the result of applying its function to a dependency key; the
+/// cached value is invalidated, and lazily recomputed, whenever the key is changed.
+pub struct Memo<T, K = (), F = fn(&K) -> T> {
+    key: K,
+    cell: OnceCell<T>,
+    func: F,
+}
+
+impl<T, K, F> Memo<T, K, F>
+where
+    F: Fn(&K) -> T,
+{
+    /// returns a new instance computing its value from the given key using the function
+    pub const fn new(key: K, func: F) -> Self {
+        Self {
+            key,
+            cell: OnceCell::new(),
+            func,
+        }
+    }
+    /// returns a reference to the memoized value, computing it if necessary
+    pub fn get(&self) -> &T {
+        self.cell.get_or_init(|| (self.func)(&self.key))
+    }
+    /// returns a mutable reference to the memoized value, computing it if necessary; any
+    /// changes are discarded once the memo is invalidated
+    pub fn get_mut(&mut self) -> &mut T {
+        Memo::get(self);
+        match self.cell.get_mut() {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+    /// consumes the current instance to return the memoized value, computing it if necessary
+    #[inline]
+    pub fn into_inner(self) -> T {
+        Memo::get(&self);
+        match self.cell.into_inner() {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, K, F> Memo<T, K, F> {
+    /// returns an immutable reference to the dependency key
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+    /// returns true if the memoized value is currently cached
+    pub fn is_init(&self) -> bool {
+        self.cell.get().is_some()
+    }
+    /// returns a reference to the memoized value, if it is currently cached
+    pub fn peek(&self) -> Option<&T> {
+        self.cell.get()
+    }
+    /// discard the cached value, forcing it to be recomputed on the next access
+    pub fn invalidate(&mut self) -> Option<T> {
+        self.cell.take()
+    }
+    /// update the dependency key, invalidating the cached value if the key has changed;
+    /// returns true if the key was changed
+    pub fn set_key(&mut self, key: K) -> bool
+    where
+        K: PartialEq,
+    {
+        if self.key == key {
+            return false;
+        }
+        self.key = key;
+        self.invalidate();
+        true
+    }
+    /// [`replace`](core::mem::replace) the dependency key, unconditionally invalidating the
+    /// cached value and returning the previous key
+    pub fn replace_key(&mut self, key: K) -> K {
+        self.invalidate();
+        core::mem::replace(&mut self.key, key)
+    }
+}
+
+impl<T, K, F> core::fmt::Debug for Memo<T, K, F>
+where
+    K: core::fmt::Debug,
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Memo")
+            .field("key", &self.key)
+            .field("value", &self.peek())
+            .finish()
+    }
+}
+
+impl<T, K, F> core::ops::Deref for Memo<T, K, F>
+where
+    F: Fn(&K) -> T,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        Memo::get(self)
+    }
+}
+
+impl<T, K, F> Get<T> for Memo<T, K, F>
+where
+    F: Fn(&K) -> T,
+{
+    fn get(&self) -> &T {
+        Memo::get(self)
+    }
+}
+
+impl<T, K, F> Wrapper<T> for Memo<T, K, F>
+where
+    F: Fn(&K) -> T,
+{
+    type Cont<U> = U;
+
+    fn get(&self) -> &T {
+        Memo::get(self)
+    }
+
+    fn view(&self) -> Self::Cont<&T> {
+        Memo::get(self)
+    }
+}
+
+impl<T, K, F> WrapperMut<T> for Memo<T, K, F>
+where
+    F: Fn(&K) -> T,
+{
+    fn get_mut(&mut self) -> &mut T {
+        Memo::get_mut(self)
+    }
+
+    fn view_mut(&mut self) -> Self::Cont<&mut T> {
+        Memo::get_mut(self)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::sync::SyncMemo;
+
+#[cfg(feature = "std")]
+mod sync {
+    use crate::traits::{Get, Wrapper, WrapperMut};
+    use std::sync::OnceLock;
+
+    /// The [`SyncMemo`] wrapper is a thread-safe variant of the [`Memo`](super::Memo) wrapper,
+    /// guaranteeing the value is computed at most once per key, even when accessed
+    /// concurrently.
+    pub struct SyncMemo<T, K = (), F = fn(&K) -> T> {
+        key: K,
+        cell: OnceLock<T>,
+        func: F,
+    }
+
+    impl<T, K, F> SyncMemo<T, K, F>
+    where
+        F: Fn(&K) -> T,
+    {
+        /// returns a new instance computing its value from the given key using the function
+        pub const fn new(key: K, func: F) -> Self {
+            Self {
+                key,
+                cell: OnceLock::new(),
+                func,
+            }
+        }
+        /// returns a reference to the memoized value, computing it if necessary
+        pub fn get(&self) -> &T {
+            self.cell.get_or_init(|| (self.func)(&self.key))
+        }
+        /// returns a mutable reference to the memoized value, computing it if necessary; any
+        /// changes are discarded once the memo is invalidated
+        pub fn get_mut(&mut self) -> &mut T {
+            SyncMemo::get(self);
+            match self.cell.get_mut() {
+                Some(value) => value,
+                None => unreachable!(),
+            }
+        }
+        /// consumes the current instance to return the memoized value, computing it if
+        /// necessary
+        #[inline]
+        pub fn into_inner(self) -> T {
+            SyncMemo::get(&self);
+            match self.cell.into_inner() {
+                Some(value) => value,
+                None => unreachable!(),
+            }
+        }
+    }
+
+    impl<T, K, F> SyncMemo<T, K, F> {
+        /// returns an immutable reference to the dependency key
+        pub const fn key(&self) -> &K {
+            &self.key
+        }
+        /// returns true if the memoized value is currently cached
+        pub fn is_init(&self) -> bool {
+            self.cell.get().is_some()
+        }
+        /// returns a reference to the memoized value, if it is currently cached
+        pub fn peek(&self) -> Option<&T> {
+            self.cell.get()
+        }
+        /// discard the cached value, forcing it to be recomputed on the next access
+        pub fn invalidate(&mut self) -> Option<T> {
+            self.cell.take()
+        }
+        /// update the dependency key, invalidating the cached value if the key has changed;
+        /// returns true if the key was changed
+        pub fn set_key(&mut self, key: K) -> bool
+        where
+            K: PartialEq,
+        {
+            if self.key == key {
+                return false;
+            }
+            self.key = key;
+            self.invalidate();
+            true
+        }
+        /// [`replace`](core::mem::replace) the dependency key, unconditionally invalidating
+        /// the cached value and returning the previous key
+        pub fn replace_key(&mut self, key: K) -> K {
+            self.invalidate();
+            core::mem::replace(&mut self.key, key)
+        }
+    }
+
+    impl<T, K, F> core::fmt::Debug for SyncMemo<T, K, F>
+    where
+        K: core::fmt::Debug,
+        T: core::fmt::Debug,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SyncMemo")
+                .field("key", &self.key)
+                .field("value", &self.peek())
+                .finish()
+        }
+    }
+
+    impl<T, K, F> core::ops::Deref for SyncMemo<T, K, F>
+    where
+        F: Fn(&K) -> T,
+    {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            SyncMemo::get(self)
+        }
+    }
+
+    impl<T, K, F> Get<T> for SyncMemo<T, K, F>
+    where
+        F: Fn(&K) -> T,
+    {
+        fn get(&self) -> &T {
+            SyncMemo::get(self)
+        }
+    }
+
+    impl<T, K, F> Wrapper<T> for SyncMemo<T, K, F>
+    where
+        F: Fn(&K) -> T,
+    {
+        type Cont<U> = U;
+
+        fn get(&self) -> &T {
+            SyncMemo::get(self)
+        }
+
+        fn view(&self) -> Self::Cont<&T> {
+            SyncMemo::get(self)
+        }
+    }
+
+    impl<T, K, F> WrapperMut<T> for SyncMemo<T, K, F>
+    where
+        F: Fn(&K) -> T,
+    {
+        fn get_mut(&mut self) -> &mut T {
+            SyncMemo::get_mut(self)
+        }
+
+        fn view_mut(&mut self) -> Self::Cont<&mut T> {
+            SyncMemo::get_mut(self)
+        }
+    }
+}
@@ -0,0 +1,264 @@
+/*
+    Appellation: observe <module>
+    Created At: 2026.10.19:10:03:17
+    Contrib: @FL03
+*/
+//! this module implements the [`Observed`] wrapper, a container that notifies a set of
+//! registered listeners whenever its inner value is modified.
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// a type alias for the boxed callbacks invoked with the previous and current values
+type Listener<T> = Box<dyn Fn(&T, &T)>;
+
+/// A [`ListenerId`] is a handle returned when subscribing to an [`Observed`] value, used to
+/// remove the listener later on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct ListenerId(usize);
+
+/// The [`Observed`] wrapper invokes each of its listeners with the previous and current value
+/// after every mutation. Several writes may be coalesced into a single notification using a
+/// [`batch`](Observed::batch).
+pub struct Observed<T> {
+    value: T,
+    listeners: Vec<(ListenerId, Listener<T>)>,
+    next_id: usize,
+    /// the value captured at the start of a batch, if any
+    pending: Option<T>,
+}
+
+/// A guard providing mutable access to the value of an [`Observed`] wrapper; the listeners
+/// are notified once the guard is dropped.
+pub struct ObservedGuard<'a, T> {
+    observed: &'a mut Observed<T>,
+    prev: Option<T>,
+}
+
+/// A [`Batch`] suspends notifications for the [`Observed`] value it borrows, notifying the
+/// listeners once, with the value from before the batch started, when dropped. Leaking the
+/// guard, e.g. using [`mem::forget`](core::mem::forget), leaves the batch open, silencing the
+/// listeners for the remaining lifetime of the value.
+pub struct Batch<'a, T> {
+    observed: &'a mut Observed<T>,
+    /// true if this instance started the batch, false if it is nested within another
+    root: bool,
+}
+
+impl ListenerId {
+    /// returns the raw value of the handle
+    pub const fn get(&self) -> usize {
+        self.0
+    }
+}
+
+impl<T> Observed<T> {
+    /// returns a new instance with the given value and no listeners
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            listeners: Vec::new(),
+            next_id: 0,
+            pending: None,
+        }
+    }
+    /// returns an immutable reference to the inner value
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+    /// returns a guard providing mutable access to the inner value; the listeners are
+    /// notified once the guard is dropped
+    pub fn get_mut(&mut self) -> ObservedGuard<'_, T>
+    where
+        T: Clone,
+    {
+        ObservedGuard {
+            prev: Some(self.value.clone()),
+            observed: self,
+        }
+    }
+    /// consumes the current instance and returns the inner value, dropping the listeners
+    #[inline]
+    pub fn value(self) -> T {
+        self.value
+    }
+    /// consumes the current instance to return the inner value; an alias of
+    /// [`value`](Self::value)
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value()
+    }
+    /// returns true if the instance is currently within a batch
+    pub const fn is_batching(&self) -> bool {
+        self.pending.is_some()
+    }
+    /// returns the number of registered listeners
+    pub const fn listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+    /// register a listener invoked with the previous and current value after each mutation,
+    /// returning a handle that may be used to [`unsubscribe`](Self::unsubscribe) it
+    pub fn subscribe<F>(&mut self, listener: F) -> ListenerId
+    where
+        F: Fn(&T, &T) + 'static,
+    {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, Box::new(listener)));
+        id
+    }
+    /// remove the listener associated with the given handle, returning true if it was found
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        let len = self.listeners.len();
+        self.listeners.retain(|(i, _)| *i != id);
+        self.listeners.len() != len
+    }
+    /// start a batch; the listeners are notified once when the returned guard is dropped
+    /// rather than after each individual mutation. The batch is never closed if the guard is
+    /// leaked rather than dropped.
+    pub fn batch(&mut self) -> Batch<'_, T>
+    where
+        T: Clone,
+    {
+        let root = self.pending.is_none();
+        if root {
+            self.pending = Some(self.value.clone());
+        }
+        Batch {
+            observed: self,
+            root,
+        }
+    }
+    /// [`replace`](core::mem::replace) the inner value with the given, returning the previous
+    /// value after notifying the listeners
+    pub fn replace(&mut self, value: T) -> T {
+        let prev = core::mem::replace(&mut self.value, value);
+        self.notify(&prev);
+        prev
+    }
+    /// set the inner value and notify the listeners
+    #[inline]
+    pub fn set(&mut self, value: T) -> &mut Self {
+        self.replace(value);
+        self
+    }
+    /// [`swap`](core::mem::swap) the inner value with that of another instance, notifying the
+    /// listeners of both
+    pub fn swap(&mut self, other: &mut Self) {
+        core::mem::swap(&mut self.value, &mut other.value);
+        self.notify(&other.value);
+        other.notify(&self.value);
+    }
+    /// [`take`](core::mem::take) the inner value, leaving a default in its place and
+    /// notifying the listeners
+    #[inline]
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+    /// invoke the listeners with the given previous value, unless a batch is in progress
+    fn notify(&self, prev: &T) {
+        if self.is_batching() {
+            return;
+        }
+        for (_, listener) in &self.listeners {
+            listener(prev, &self.value);
+        }
+    }
+}
+
+impl<T> Default for Observed<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for Observed<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> AsRef<T> for Observed<T> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> core::borrow::Borrow<T> for Observed<T> {
+    fn borrow(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> core::ops::Deref for Observed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T> core::fmt::Debug for Observed<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Observed")
+            .field("value", &self.value)
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
+impl<T> core::ops::Deref for ObservedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.observed.value
+    }
+}
+
+impl<T> core::ops::DerefMut for ObservedGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.observed.value
+    }
+}
+
+impl<T> Drop for ObservedGuard<'_, T> {
+    fn drop(&mut self) {
+        if let Some(prev) = self.prev.take() {
+            self.observed.notify(&prev);
+        }
+    }
+}
+
+impl<T> core::ops::Deref for Batch<'_, T> {
+    type Target = Observed<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.observed
+    }
+}
+
+impl<T> core::ops::DerefMut for Batch<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.observed
+    }
+}
+
+impl<T> Drop for Batch<'_, T> {
+    fn drop(&mut self) {
+        if !self.root {
+            return;
+        }
+        if let Some(prev) = self.observed.pending.take() {
+            self.observed.notify(&prev);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/*
+    appellation: atomic <test>
+    authors: @FL03
+*/
+use contained_core::traits::AtomicRepr;
+use contained_core::wrappers::Atomic;
+use core::sync::atomic::Ordering;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+enum State {
+    Idle = 0,
+    Running = 1,
+    Done = 2,
+}
+
+impl AtomicRepr for State {
+    type Repr = u8;
+
+    fn into_repr(self) -> u8 {
+        self as u8
+    }
+
+    fn from_repr(repr: u8) -> Self {
+        match repr {
+            0 => Self::Idle,
+            1 => Self::Running,
+            _ => Self::Done,
+        }
+    }
+}
+
+#[test]
+fn test_atomic_enum() {
+    let state = Atomic::new(State::Idle);
+    assert_eq!(state.load(Ordering::Acquire), State::Idle);
+    assert_eq!(
+        state.compare_exchange(State::Idle, State::Running, Ordering::AcqRel, Ordering::Acquire),
+        Ok(State::Idle)
+    );
+    assert_eq!(
+        state.compare_exchange(State::Idle, State::Done, Ordering::AcqRel, Ordering::Acquire),
+        Err(State::Running)
+    );
+    assert_eq!(state.swap(State::Done, Ordering::AcqRel), State::Running);
+    assert_eq!(state.into_inner(), State::Done);
+}
+
+#[test]
+fn test_atomic_fetch_update() {
+    let counter = Atomic::new(-2_i32);
+    let res = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |v| Some(v + 5));
+    assert_eq!(res, Ok(-2));
+    assert_eq!(counter.load(Ordering::Acquire), 3);
+    let res = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |_| None);
+    assert_eq!(res, Err(3));
+    counter.store(i32::MIN, Ordering::Release);
+    assert_eq!(counter.load(Ordering::Acquire), i32::MIN);
+}
@@ -0,0 +1,56 @@
+/*
+    appellation: cell <test>
+    authors: @FL03
+*/
+use contained_core::Error;
+use contained_core::cell::{Local, Shared, Sync};
+use contained_core::traits::InteriorMut;
+
+fn bump<W: InteriorMut<usize>>(wrapper: &W) -> contained_core::Result<usize> {
+    wrapper.with_mut(|v| *v += 1)?;
+    wrapper.with(|v| *v)
+}
+
+#[test]
+fn test_uniform_interface() -> contained_core::Result<()> {
+    let (local, shared, sync) = (Local::new(1), Shared::new(1), Sync::new(1));
+    assert_eq!(bump(&local)?, 2);
+    assert_eq!(bump(&shared)?, 2);
+    assert_eq!(bump(&sync)?, 2);
+
+    assert_eq!(local.replace(10)?, 2);
+    assert_eq!(shared.take()?, 2);
+    sync.set(5)?;
+    assert_eq!(sync.map(|v| v * 2)?.with(|v| *v)?, 10);
+    assert_eq!(local.map(|v| v.to_string())?.into_inner(), "10");
+    Ok(())
+}
+
+#[test]
+fn test_shared_handles() -> contained_core::Result<()> {
+    let a = Shared::new(vec![1]);
+    let b = a.clone();
+    b.with_mut(|v| v.push(2))?;
+    assert!(a.ptr_eq(&b));
+    assert_eq!(a.with(|v| v.len())?, 2);
+    // conflicting borrows are reported rather than panicking
+    let res = a.with(|_| b.with_mut(|v| v.clear()));
+    assert!(matches!(res, Ok(Err(Error::BorrowMutError(_)))));
+    drop(b);
+    assert_eq!(a.try_into_inner().ok(), Some(vec![1, 2]));
+    Ok(())
+}
+
+#[test]
+fn test_sync_poisoned() {
+    let sync = Sync::new(0_u8);
+    let handle = sync.clone();
+    let _ = std::thread::spawn(move || {
+        handle
+            .with_mut(|_| panic!("poison the lock"))
+            .expect("the lock is not poisoned yet");
+    })
+    .join();
+    assert!(sync.is_poisoned());
+    assert!(matches!(sync.with(|v| *v), Err(Error::Poisoned)));
+}
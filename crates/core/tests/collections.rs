@@ -0,0 +1,146 @@
+/*
+    appellation: collections <test>
+    authors: @FL03
+*/
+use contained_core::collections::{Entry, EntryRef, WrapperMap, WrapperSet};
+use contained_core::traits::Newtype;
+
+/// a wrapper whose own equality ignores case, which the collections must not rely upon
+#[derive(Clone, Debug)]
+pub struct Name(String);
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Newtype for Name {
+    type Inner = String;
+
+    const NAME: &'static str = "Name";
+
+    fn from_inner(inner: String) -> Self {
+        Self(inner)
+    }
+
+    fn as_inner(&self) -> &String {
+        &self.0
+    }
+
+    fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+fn name(value: &str) -> Name {
+    Name(value.to_string())
+}
+
+#[test]
+fn test_map_query_by_inner() {
+    let mut map = WrapperMap::new();
+    assert_eq!(map.insert(name("alice"), 1), None);
+    assert_eq!(map.insert(name("Alice"), 2), None);
+    assert_eq!(map.insert(name("alice"), 3), Some(1));
+    assert_eq!(map.len(), 2);
+    // lookups accept both the inner type and any equivalent borrowed form
+    assert_eq!(map.get("alice"), Some(&3));
+    assert_eq!(map.get(&String::from("Alice")), Some(&2));
+    assert!(!map.contains_key("bob"));
+    assert_eq!(map["Alice"], 2);
+
+    *map.get_mut("Alice").unwrap() += 10;
+    let (key, value) = map.get_key_value("Alice").unwrap();
+    assert_eq!((key.as_inner().as_str(), *value), ("Alice", 12));
+
+    let (key, value) = map.remove_entry("alice").unwrap();
+    assert_eq!((key.into_inner(), value), (String::from("alice"), 3));
+    assert_eq!(map.remove("alice"), None);
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_map_entry() {
+    let mut map = WrapperMap::<Name, usize>::new();
+    for word in ["a", "b", "a", "c", "a"] {
+        map.entry(name(word)).and_modify(|n| *n += 1).or_insert(1);
+    }
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 1);
+
+    match map.entry(name("b")) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_entry().1, 1),
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+    match map.entry(name("d")) {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key().into_inner(), "d"),
+        Entry::Occupied(_) => panic!("expected a vacant entry"),
+    }
+    assert_eq!(*map.entry(name("e")).or_insert_with_key(|k| k.0.len()), 1);
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn test_map_entry_ref() {
+    let mut map = WrapperMap::<Name, Vec<u8>>::new();
+    map.entry_ref("x").or_default().push(1);
+    map.entry_ref("x").or_default().push(2);
+    assert_eq!(map["x"], vec![1, 2]);
+
+    match map.entry_ref("y") {
+        EntryRef::Vacant(entry) => {
+            assert_eq!(entry.key(), "y");
+            entry.insert(vec![3]);
+        }
+        EntryRef::Occupied(_) => panic!("expected a vacant entry"),
+    }
+    assert_eq!(map.get_key_value("y").unwrap().0.as_inner(), "y");
+}
+
+#[test]
+fn test_map_find_by_hash() {
+    let mut map: WrapperMap<Name, i32> = [(name("a"), 1), (name("b"), 2)].into_iter().collect();
+    let hash = map.hash_one("b");
+    assert_eq!(map.hash_one(&String::from("b")), hash);
+    let (key, value) = map.find_by_hash(hash, |k| k.0 == "b").unwrap();
+    assert_eq!((key.0.as_str(), *value), ("b", 2));
+    assert!(map.find_by_hash(hash, |k| k.0 == "c").is_none());
+
+    *map.find_by_hash_mut(hash, |k| k.0 == "b")
+        .unwrap()
+        .get_mut() = 5;
+    assert_eq!(map["b"], 5);
+}
+
+#[test]
+fn test_map_iter() {
+    let mut map: WrapperMap<Name, i32> = (0..4).map(|i| (name(&i.to_string()), i)).collect();
+    map.values_mut().for_each(|v| *v *= 2);
+    map.retain(|_, v| *v != 0);
+    assert_eq!(map.iter().len(), 3);
+    assert_eq!(map.values().sum::<i32>(), 12);
+
+    let mut keys = map.into_iter().map(|(k, _)| k.0).collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, ["1", "2", "3"]);
+}
+
+#[test]
+fn test_set() {
+    let mut set: WrapperSet<Name> = [name("a"), name("A")].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(!set.insert(name("a")));
+    assert!(set.contains("a") && set.contains("A"));
+
+    assert_eq!(set.get_or_insert_ref("b").as_inner(), "b");
+    assert_eq!(set.len(), 3);
+    assert!(set.replace(name("b")).is_some());
+    assert_eq!(set.take("A").map(Name::into_inner), Some(String::from("A")));
+    assert!(set.remove("a"));
+    assert!(!set.remove("a"));
+
+    let hash = set.hash_one("b");
+    assert!(set.find_by_hash(hash, |k| k.0 == "b").is_some());
+    assert_eq!(set.iter().map(|k| k.0.as_str()).collect::<Vec<_>>(), ["b"]);
+}
@@ -0,0 +1,54 @@
+/*
+    appellation: complex <test>
+    authors: @FL03
+*/
+use contained_core::complex::{Complex, Phasor};
+use core::f64::consts::FRAC_PI_2;
+
+const EPS: f64 = 1e-12;
+
+#[test]
+fn test_phasor_arithmetic() {
+    let (a, b) = (Phasor::from_rect(1.0, 2.0), Phasor::from_rect(3.0, -1.0));
+    assert_eq!(a + b, Phasor(Complex::new(4.0, 1.0)));
+    assert_eq!(a - b, Phasor(Complex::new(-2.0, 3.0)));
+    assert_eq!(a * b, Phasor(Complex::new(5.0, 5.0)));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(-a, Phasor(Complex::new(-1.0, -2.0)));
+    assert_eq!([a, b].iter().sum::<Phasor<Complex<f64>>>(), a + b);
+}
+
+#[test]
+fn test_phasor_scalar_arithmetic() {
+    let v = Phasor::from_rect(3.0_f64, 4.0);
+    assert_eq!(v * 2.0, Phasor(Complex::new(6.0, 8.0)));
+    assert_eq!(2.0 * v, Phasor(Complex::new(6.0, 8.0)));
+    assert_eq!(v / 2.0, Phasor(Complex::new(1.5, 2.0)));
+    assert_eq!(v + 1.0, Phasor(Complex::new(4.0, 4.0)));
+    assert_eq!(1.0 - v, Phasor(Complex::new(-2.0, -4.0)));
+    assert_eq!(
+        Phasor::from_rect(1.0_f32, 1.0) * 3.0,
+        Phasor::from_rect(3.0, 3.0)
+    );
+
+    let mut w = v;
+    w *= 2.0;
+    w -= 6.0;
+    assert_eq!(w, Phasor(Complex::new(0.0, 8.0)));
+}
+
+#[test]
+fn test_phasor_forwarded_methods() {
+    let v = Phasor::from_rect(3.0_f64, 4.0);
+    assert_eq!(v.conj(), Phasor(Complex::new(3.0, -4.0)));
+    assert_eq!(v.norm(), 5.0);
+    assert!((v.arg() - 4.0_f64.atan2(3.0)).abs() < EPS);
+
+    let i = Phasor::from_polar(1.0, FRAC_PI_2);
+    let square = i.powf(2.0);
+    assert!((square.0 - Complex::new(-1.0, 0.0)).norm() < EPS);
+    // scaling by a positive real preserves the phase
+    assert!(((i * 3.0).arg() - FRAC_PI_2).abs() < EPS);
+    let (r, theta) = (i * 3.0).to_polar();
+    assert!((r - 3.0).abs() < EPS && (theta - FRAC_PI_2).abs() < EPS);
+}
@@ -0,0 +1,89 @@
+/*
+    appellation: error <test>
+    authors: @FL03
+*/
+use contained_core::{Error, ErrorKind, Result, ResultExt};
+use core::error::Error as _;
+
+fn parse_port(input: &str) -> Result<u16> {
+    let port: u16 = input.parse().context("reading the port")?;
+    if port == 0 {
+        return Err(Error::validation("Port", "the port must be non-zero"));
+    }
+    Ok(port)
+}
+
+#[test]
+fn test_error_kind() {
+    assert_eq!(Error::NaN.kind(), ErrorKind::NaN);
+    assert_eq!(Error::Poisoned.kind(), ErrorKind::Poisoned);
+    assert_eq!(Error::unknown("oops").kind(), ErrorKind::Other);
+    assert_eq!(
+        Error::from("abc".parse::<f32>().unwrap_err()).kind(),
+        ErrorKind::Parse
+    );
+    assert_eq!(
+        Error::from(u8::try_from(300_u16).unwrap_err()).kind(),
+        ErrorKind::Conversion
+    );
+    assert!(Error::conversion::<u16, u8>().is(ErrorKind::Conversion));
+    assert_eq!(
+        Error::conversion::<u16, u8>().to_string(),
+        "unable to convert `u16` into `u8`"
+    );
+}
+
+#[test]
+fn test_variants() {
+    let err = parse_port("0").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Validation);
+    assert_eq!(
+        err.to_string(),
+        "the value is not a valid `Port`: the port must be non-zero"
+    );
+    let err = Error::parse("Millis", "expected the suffix `ms`");
+    assert!(err.is(ErrorKind::Parse));
+    assert_eq!(
+        err.to_string(),
+        "unable to parse `Millis`: expected the suffix `ms`"
+    );
+    let lock = std::sync::Mutex::new(0);
+    let _ = std::panic::catch_unwind(|| {
+        let _guard = lock.lock().unwrap();
+        panic!("poisoning the lock");
+    });
+    let err = Error::from(lock.lock().unwrap_err());
+    assert_eq!(err.kind(), ErrorKind::Poisoned);
+}
+
+#[test]
+fn test_context() {
+    assert_eq!(parse_port("8080").unwrap(), 8080);
+    let err = parse_port("http").unwrap_err();
+    // the context inherits the kind of its source
+    assert_eq!(err.kind(), ErrorKind::Parse);
+    assert_eq!(err.to_string(), "reading the port");
+    let source = err.source().expect("the source is retained");
+    assert_eq!(source.to_string(), "invalid digit found in string");
+
+    let mut calls = 0;
+    let ok: Result<u8> = Ok::<_, Error>(1).with_context(|| {
+        calls += 1;
+        "never evaluated"
+    });
+    assert_eq!(ok.unwrap(), 1);
+    assert_eq!(calls, 0);
+
+    let err = Err::<(), _>(Error::NaN)
+        .with_context(|| format!("sample {}", 3))
+        .context("loading the dataset")
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NaN);
+    let chain = core::iter::successors(Some(&err as &dyn core::error::Error), |e| (*e).source())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chain,
+        ["loading the dataset", "sample 3", "the value is NaN"]
+    );
+}
@@ -0,0 +1,100 @@
+/*
+    appellation: float <test>
+    authors: @FL03
+*/
+use contained_core::wrappers::{NotNan, TotalOrd};
+use std::collections::{BTreeMap, HashSet};
+
+#[test]
+fn test_total_ord() {
+    let mut values = [
+        TotalOrd(1.0_f64),
+        TotalOrd(f64::NAN),
+        TotalOrd(-0.0),
+        TotalOrd(0.0),
+        TotalOrd(f64::NEG_INFINITY),
+    ];
+    values.sort();
+    assert_eq!(values[0], TotalOrd(f64::NEG_INFINITY));
+    assert!(values[1].get().is_sign_negative() && *values[1].get() == 0.0);
+    assert!(values[4].get().is_nan());
+    assert_eq!(TotalOrd(f64::NAN), TotalOrd(f64::NAN));
+    assert_ne!(TotalOrd(-0.0_f32), TotalOrd(0.0));
+
+    let mut map = BTreeMap::new();
+    map.insert(TotalOrd(2.5_f64), "a");
+    map.insert(TotalOrd(f64::NAN), "nan");
+    assert_eq!(map.get(&TotalOrd(f64::NAN)), Some(&"nan"));
+
+    assert_eq!(TotalOrd(1.5) + TotalOrd(2.0), TotalOrd(3.5));
+    assert_eq!(-TotalOrd(1.5_f32), TotalOrd(-1.5));
+    assert_eq!(
+        [TotalOrd(1.0), TotalOrd(2.0)].iter().sum::<TotalOrd<f64>>(),
+        TotalOrd(3.0)
+    );
+}
+
+#[test]
+fn test_not_nan() {
+    assert!(NotNan::try_new(f64::NAN).is_err());
+    let x = NotNan::try_new(2.0_f64).unwrap();
+    let y = NotNan::new(0.5);
+    assert_eq!(x * y, NotNan::new(1.0));
+    assert_eq!(x - 1.0, NotNan::new(1.0));
+
+    let mut z = x;
+    z += y;
+    z /= 5.0;
+    assert_eq!(z.into_inner(), 0.5);
+
+    let set = [x, y, x].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&NotNan::new(0.5)));
+    assert!(x > y);
+}
+
+#[test]
+#[should_panic]
+fn test_not_nan_panics_on_nan_result() {
+    let inf = NotNan::new(f64::INFINITY);
+    let _ = inf - inf;
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_not_nan_borrowed_ops() {
+    let (x, y) = (NotNan::new(3.0_f32), NotNan::new(1.5_f32));
+    assert_eq!(&x / &y, NotNan::new(2.0));
+    assert_eq!(&x + y, NotNan::new(4.5));
+    assert_eq!(6.0 / x, NotNan::new(2.0));
+    assert_eq!([x, y].iter().sum::<NotNan<f32>>(), NotNan::new(4.5));
+    assert_eq!(
+        [x, y].into_iter().product::<NotNan<f32>>(),
+        NotNan::new(4.5)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_not_nan_assign_panics_on_nan_result() {
+    let mut zero = NotNan::new(0.0_f64);
+    zero /= 0.0;
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_float_serde() {
+    let value = serde_json::to_string(&NotNan::new(1.5_f64)).unwrap();
+    assert_eq!(value, "1.5");
+    let parsed: NotNan<f64> = serde_json::from_str(&value).unwrap();
+    assert_eq!(parsed, NotNan::new(1.5));
+    // json cannot represent NaN, so deserialize from a raw value instead
+    let nan = serde::de::IntoDeserializer::<serde::de::value::Error>::into_deserializer(f64::NAN);
+    assert!(<NotNan<f64> as serde::Deserialize>::deserialize(nan).is_err());
+
+    let value = serde_json::to_string(&TotalOrd(2.0_f32)).unwrap();
+    assert_eq!(
+        serde_json::from_str::<TotalOrd<f32>>(&value).unwrap(),
+        TotalOrd(2.0)
+    );
+}
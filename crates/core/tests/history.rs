@@ -0,0 +1,52 @@
+/*
+    appellation: history <test>
+    authors: @FL03
+*/
+use contained_core::wrappers::{EvictionPolicy, History};
+
+#[test]
+fn test_history_undo_redo() {
+    let mut history = History::new(0);
+    history.set(1).set(2).set(3);
+    assert_eq!(history.history_len(), 3);
+    assert!(history.undo());
+    assert_eq!(history.get(), &2);
+    assert!(history.undo());
+    assert_eq!(history.get(), &1);
+    assert!(history.redo());
+    assert_eq!(history.get(), &2);
+    // recording a new value invalidates the redo stack
+    assert_eq!(history.replace(10), 2);
+    assert!(!history.redo());
+    assert!(history.undo());
+    assert_eq!(history.get(), &2);
+}
+
+#[test]
+fn test_history_guard() {
+    let mut history = History::new(vec![1, 2]);
+    history.get_mut().push(3);
+    assert_eq!(history.get(), &[1, 2, 3]);
+    assert_eq!(history.history_len(), 1);
+    history.checkpoint();
+    assert_eq!(history.history_len(), 2);
+    assert!(history.undo());
+    assert!(history.undo());
+    assert_eq!(history.get(), &[1, 2]);
+}
+
+#[test]
+fn test_history_eviction() {
+    let mut oldest = History::with_capacity(0, 2);
+    oldest.set(1).set(2).set(3);
+    assert_eq!(oldest.snapshots().copied().collect::<Vec<_>>(), [1, 2]);
+
+    let mut newest = History::with_capacity(0, 2).with_policy(EvictionPolicy::DropNewest);
+    newest.set(1).set(2).set(3);
+    assert_eq!(newest.snapshots().copied().collect::<Vec<_>>(), [0, 2]);
+
+    let mut skip = History::with_capacity(0, 2).with_policy(EvictionPolicy::Skip);
+    skip.set(1).set(2).set(3);
+    assert_eq!(skip.snapshots().copied().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(skip.get(), &3);
+}
@@ -0,0 +1,94 @@
+/*
+    appellation: key <test>
+    authors: @FL03
+*/
+use contained_core::traits::{CaseInsensitive, KeyFn, Rev};
+use contained_core::wrappers::ByKey;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id(u32);
+
+#[derive(Clone, Debug)]
+pub struct User {
+    pub id: Id,
+    pub name: &'static str,
+    pub timestamp: u64,
+}
+
+/// projects the timestamp of a user
+pub struct Timestamp;
+
+impl KeyFn<User> for Id {
+    type Key<'a>
+        = &'a Id
+    where
+        User: 'a;
+
+    fn key(user: &User) -> &Id {
+        &user.id
+    }
+}
+
+impl KeyFn<User> for Timestamp {
+    type Key<'a>
+        = u64
+    where
+        User: 'a;
+
+    fn key(user: &User) -> u64 {
+        user.timestamp
+    }
+}
+
+const fn user(id: u32, name: &'static str, timestamp: u64) -> User {
+    User {
+        id: Id(id),
+        name,
+        timestamp,
+    }
+}
+
+#[test]
+fn test_by_id() {
+    let mut users = HashSet::<ByKey<User, Id>>::new();
+    assert!(users.insert(ByKey::new(user(1, "alice", 10))));
+    assert!(users.insert(ByKey::new(user(2, "bob", 20))));
+    assert!(!users.insert(ByKey::new(user(1, "carol", 30))));
+    assert_eq!(users.len(), 2);
+    // lookup by the key alone
+    assert_eq!(users.get(&Id(1)).map(|u| u.name), Some("alice"));
+    assert!(users.contains(&Id(2)));
+    assert!(!users.contains(&Id(3)));
+}
+
+#[test]
+fn test_reverse_by_timestamp() {
+    let users = [
+        user(1, "alice", 10),
+        user(2, "bob", 30),
+        user(3, "carol", 20),
+    ]
+    .into_iter()
+    .map(ByKey::<User, Rev<Timestamp>>::new)
+    .collect::<BTreeSet<_>>();
+    let names = users.iter().map(|u| u.name).collect::<Vec<_>>();
+    assert_eq!(names, ["bob", "carol", "alice"]);
+}
+
+#[test]
+fn test_case_insensitive() {
+    let a = ByKey::<&str, CaseInsensitive>::new("Hello");
+    let b = ByKey::<&str, CaseInsensitive>::new("hELLO");
+    assert_eq!(a, b);
+    assert!(ByKey::<String, CaseInsensitive>::new("apple".into()) < "Banana".to_string().into());
+
+    let mut counts = HashMap::new();
+    for word in ["Rust", "rust", "RUST", "go"] {
+        *counts
+            .entry(ByKey::<_, CaseInsensitive>::new(word))
+            .or_insert(0) += 1;
+    }
+    assert_eq!(counts[&ByKey::new("rUsT")], 3);
+    assert_eq!(counts.len(), 2);
+}
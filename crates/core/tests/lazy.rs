@@ -0,0 +1,72 @@
+/*
+    appellation: lazy <test>
+    authors: @FL03
+*/
+use contained_core::traits::{Get, Wrapper};
+use contained_core::wrappers::{Lazy, Memo};
+use core::cell::Cell;
+
+fn read<W: Wrapper<usize>>(wrapper: &W) -> usize {
+    *wrapper.get()
+}
+
+#[test]
+fn test_lazy() {
+    let calls = Cell::new(0);
+    let mut lazy = Lazy::new(|| {
+        calls.set(calls.get() + 1);
+        42_usize
+    });
+    assert!(!lazy.is_init());
+    assert_eq!(lazy.peek(), None);
+    assert_eq!(read(&lazy), 42);
+    assert_eq!(*Get::get(&lazy), 42);
+    *lazy.get_mut() += 1;
+    assert_eq!(lazy.into_inner(), 43);
+    assert_eq!(calls.get(), 1);
+    // eager values are accepted by the same generic code
+    assert_eq!(read(&42_usize), 42);
+}
+
+#[test]
+fn test_memo() {
+    let calls = Cell::new(0);
+    let mut memo = Memo::new(2_usize, |k: &usize| {
+        calls.set(calls.get() + 1);
+        k * 10
+    });
+    assert_eq!(read(&memo), 20);
+    assert_eq!(memo.get(), &20);
+    assert_eq!(calls.get(), 1);
+    // setting the same key keeps the cached value
+    assert!(!memo.set_key(2));
+    assert!(memo.is_init());
+    assert!(memo.set_key(3));
+    assert!(!memo.is_init());
+    assert_eq!(memo.get(), &30);
+    assert_eq!(calls.get(), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sync_lazy() {
+    use contained_core::wrappers::{SyncLazy, SyncMemo};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static LAZY: SyncLazy<usize> = SyncLazy::new(|| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        7
+    });
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| assert_eq!(read(&LAZY), 7));
+        }
+    });
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    let mut memo = SyncMemo::new("a", |k: &&str| k.len());
+    assert_eq!(memo.get(), &1);
+    memo.set_key("abc");
+    assert_eq!(memo.get(), &3);
+}
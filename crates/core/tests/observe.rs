@@ -0,0 +1,76 @@
+/*
+    appellation: observe <test>
+    authors: @FL03
+*/
+use contained_core::wrappers::Observed;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn recorder<T: Copy + 'static>(observed: &mut Observed<T>) -> Rc<RefCell<Vec<(T, T)>>> {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let sink = log.clone();
+    observed.subscribe(move |prev: &T, curr: &T| sink.borrow_mut().push((*prev, *curr)));
+    log
+}
+
+#[test]
+fn test_observed_notifies() {
+    let mut observed = Observed::new(1);
+    let log = recorder(&mut observed);
+    observed.set(2);
+    assert_eq!(observed.replace(3), 2);
+    *observed.get_mut() += 1;
+    assert_eq!(observed.take(), 4);
+    assert_eq!(*log.borrow(), [(1, 2), (2, 3), (3, 4), (4, 0)]);
+}
+
+#[test]
+fn test_observed_swap() {
+    let (mut a, mut b) = (Observed::new('a'), Observed::new('b'));
+    let (log_a, log_b) = (recorder(&mut a), recorder(&mut b));
+    a.swap(&mut b);
+    assert_eq!(*log_a.borrow(), [('a', 'b')]);
+    assert_eq!(*log_b.borrow(), [('b', 'a')]);
+}
+
+#[test]
+fn test_observed_unsubscribe() {
+    let mut observed = Observed::new(0);
+    let log = Rc::new(RefCell::new(0));
+    let sink = log.clone();
+    let id = observed.subscribe(move |_, _| *sink.borrow_mut() += 1);
+    observed.set(1);
+    assert!(observed.unsubscribe(id));
+    assert!(!observed.unsubscribe(id));
+    observed.set(2);
+    assert_eq!(*log.borrow(), 1);
+    assert_eq!(observed.listener_count(), 0);
+}
+
+#[test]
+fn test_observed_batch() {
+    let mut observed = Observed::new(0);
+    let log = recorder(&mut observed);
+    {
+        let mut batch = observed.batch();
+        batch.set(1).set(2);
+        *batch.get_mut() *= 10;
+        // nested batches are folded into the outer one
+        batch.batch().set(30);
+        assert!(log.borrow().is_empty());
+    }
+    assert!(!observed.is_batching());
+    assert_eq!(*log.borrow(), [(0, 30)]);
+}
+
+#[test]
+fn test_observed_leaked_batch() {
+    let mut observed = Observed::new(0);
+    let log = recorder(&mut observed);
+    core::mem::forget(observed.batch());
+    observed.set(1);
+    // the batch is never closed, leaving the listeners silent
+    assert!(observed.is_batching());
+    assert!(log.borrow().is_empty());
+    assert_eq!(observed.value(), 1);
+}
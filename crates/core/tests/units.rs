@@ -0,0 +1,78 @@
+/*
+    appellation: units <test>
+    authors: @FL03
+*/
+use contained_core::units::*;
+
+/// asserts, at compile time, that the unit describes an area
+fn assert_area<U: Unit<Dim = Area>>() {}
+
+#[test]
+fn test_type_level_integers() {
+    assert_eq!(<<P2 as IntAdd<N3>>::Output as Integer>::VALUE, -1);
+    assert_eq!(<<N1 as IntSub<P3>>::Output as Integer>::VALUE, -4);
+    assert_eq!(<<P4 as IntNeg>::Output as Integer>::VALUE, -4);
+    assert_eq!(<<Z0 as IntAdd<P4>>::Output as Integer>::VALUE, 4);
+}
+
+#[test]
+fn test_quantity_arithmetic() {
+    let a = Quantity::<f64, Meter>::new(3.0);
+    let b = Quantity::<f64, Meter>::new(4.0);
+    assert_eq!(a + b, Quantity::new(7.0));
+    assert_eq!(b - a, Quantity::new(1.0));
+    assert_eq!(-a, Quantity::new(-3.0));
+    assert_eq!(a * 2.0, Quantity::new(6.0));
+    assert_eq!(2.0 * a, Quantity::new(6.0));
+
+    let mut c = a;
+    c += b;
+    c /= 7.0;
+    assert_eq!(c, Quantity::new(1.0));
+
+    let area: Quantity<f64, Prod<Meter, Meter>> = a * b;
+    assert_eq!(*area.get(), 12.0);
+    assert_area::<Prod<Meter, Meter>>();
+    assert_area::<Per<Prod<Meter, Prod<Meter, Meter>>, Meter>>();
+
+    let speed: Quantity<f64, Per<Meter, Second>> = a / Quantity::<f64, Second>::new(2.0);
+    assert_eq!(speed.into_inner(), 1.5);
+
+    let total = [a, b, c].into_iter().sum::<Quantity<f64, Meter>>();
+    assert_eq!(total, Quantity::new(8.0));
+    // borrowed quantities
+    assert_eq!(-&a, Quantity::new(-3.0));
+    assert_eq!(&b / 2.0, Quantity::new(2.0));
+    assert_eq!(
+        [a, b].iter().sum::<Quantity<f64, Meter>>(),
+        Quantity::new(7.0)
+    );
+}
+
+#[test]
+fn test_quantity_conversion() {
+    let distance = Quantity::<f64, Kilometer>::new(1.5);
+    assert_eq!(distance.convert::<Meter>(), Quantity::new(1500.0));
+    assert_eq!(distance.convert::<Centimeter>(), Quantity::new(150_000.0));
+
+    let time = Quantity::<f64, Hour>::new(0.5).convert::<Minute>();
+    assert_eq!(time, Quantity::new(30.0));
+
+    let speed = Quantity::<f64, Per<Meter, Second>>::new(10.0);
+    assert_eq!(speed.convert::<Per<Kilometer, Hour>>(), Quantity::new(36.0));
+
+    let force = Quantity::<f64, Per<Prod<Kilogram, Meter>, Prod<Second, Second>>>::new(2.0);
+    assert_eq!(force.convert::<Newton>(), Quantity::new(2.0));
+}
+
+#[test]
+fn test_quantity_display() {
+    let a = Quantity::<f64, Meter>::new(3.0);
+    assert_eq!(a.to_string(), "3 m");
+    assert_eq!(format!("{:.2}", a), "3.00 m");
+    assert_eq!((a * a).to_string(), "9 m·m");
+    assert_eq!(
+        format!("{:?}", Quantity::<i32, Per<Kilometer, Hour>>::new(5)),
+        "5 km/h"
+    );
+}
@@ -4,22 +4,106 @@
     Contrib: @FL03
 */
 mod impl_display_attr;
+mod impl_from_str_attr;
 mod impl_nested_attr;
+mod impl_par_attr;
+mod impl_rand_attr;
+mod impl_schema_bound;
+mod impl_serde_mode;
+mod impl_wasm_attr;
 mod impl_wrapper_attr;
 
-use syn::Ident;
+use syn::{Expr, ExprRange, Ident, Path};
 
 /// AST for the root attribute
-#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Default)]
 pub struct WrapperAttr {
     pub inner: Option<DisplayAttr>,
+    /// a toggle indicating whether to implement `AtomicRepr` by forwarding to the field
+    pub atomic: bool,
+    /// the options used when implementing `FromStr`
+    pub from_str: Option<FromStrAttr>,
+    /// the options used when implementing the parallel iterator traits of `rayon`
+    pub par: Option<ParAttr>,
+    /// the representation used when implementing `Serialize` and `Deserialize`
+    pub serde: Option<SerdeMode>,
+    /// the path to a checked constructor the inner value is deserialized through
+    pub validate: Option<Path>,
+    /// the validation bounds of the JSON schema, if one is to be generated
+    pub schema: Option<Vec<SchemaBound>>,
+    /// the options used when implementing `Distribution` for `StandardUniform`
+    pub rand: Option<RandAttr>,
+    /// the options used when implementing the ABI conversions of `wasm-bindgen`
+    pub wasm: Option<WasmAttr>,
 }
 
 /// [`NestedAttr`] is an enumeration of various nested attributes the crate recognizes.
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone)]
 pub enum NestedAttr {
+    Atomic,
+    FromStr(FromStrAttr),
     Inner(DisplayAttr),
+    Par(ParAttr),
+    Rand(RandAttr),
+    Schema(Vec<SchemaBound>),
+    Serde(SerdeMode),
+    Validate(Path),
+    Wasm(WasmAttr),
+}
+
+/// [`FromStrAttr`] is the AST for `#[wrap(from_str)]` and
+/// `#[wrap(from_str(prefix = "...", suffix = "..."))]`
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FromStrAttr {
+    /// the prefix stripped from the input before parsing the inner value, if any
+    pub prefix: Option<String>,
+    /// the suffix stripped from the input before parsing the inner value, if any
+    pub suffix: Option<String>,
+}
+
+/// [`ParAttr`] is the AST for `#[wrap(par)]` and `#[wrap(par(sum, product))]`
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParAttr {
+    /// a toggle indicating whether to implement `Sum` for owned and borrowed wrappers
+    pub sum: bool,
+    /// a toggle indicating whether to implement `Product` for owned and borrowed wrappers
+    pub product: bool,
+}
+
+/// [`RandAttr`] is the AST for `#[wrap(rand)]` and `#[wrap(rand(range = lo..hi))]`
+#[derive(Clone, Default)]
+pub struct RandAttr {
+    /// the range the inner value is uniformly sampled from, if any
+    pub range: Option<ExprRange>,
+}
+
+/// [`SchemaBound`] is a single validation keyword of `#[wrap(schema(...))]`, e.g.
+/// `minimum = 0`
+#[derive(Clone)]
+pub struct SchemaBound {
+    /// the name of the JSON schema keyword, e.g. `exclusiveMinimum`
+    pub keyword: String,
+    /// the literal value assigned to the keyword
+    pub value: Expr,
+}
+
+/// [`SerdeMode`] enumerates the representations available to `#[wrap(serde = "...")]`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SerdeMode {
+    /// the wrapper is represented by its inner value
+    Transparent,
+    /// the inner value is tagged with the name of the wrapper
+    Tagged,
+    /// the wrapper is represented as a struct with a single field
+    Struct,
+}
+
+/// [`WasmAttr`] is the AST for `#[wrap(wasm)]` and `#[wrap(wasm(option))]`
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WasmAttr {
+    /// a toggle indicating whether to implement `OptionIntoWasmAbi` and `OptionFromWasmAbi`
+    pub option: bool,
 }
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -0,0 +1,33 @@
+/*
+    Appellation: impl_from_str_attr <module>
+    Created At: 2026.10.19:21:04:37
+    Contrib: @FL03
+*/
+use crate::attrs::FromStrAttr;
+use syn::LitStr;
+use syn::meta::ParseNestedMeta;
+
+impl FromStrAttr {
+    /// attempts to parse the attribute, along with its optional parameters, from the given
+    /// metadata
+    pub fn parse_nested(meta: &ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        // #[wrap(from_str)]
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(attr);
+        }
+        // #[wrap(from_str(prefix = "...", suffix = "..."))]
+        meta.parse_nested_meta(|param| {
+            if param.path.is_ident("prefix") {
+                attr.prefix = Some(param.value()?.parse::<LitStr>()?.value());
+                return Ok(());
+            }
+            if param.path.is_ident("suffix") {
+                attr.suffix = Some(param.value()?.parse::<LitStr>()?.value());
+                return Ok(());
+            }
+            Err(param.error("unrecognized parameter"))
+        })?;
+        Ok(attr)
+    }
+}
@@ -3,27 +3,71 @@
     Created At: 2026.01.20:14:25:15
     Contrib: @FL03
 */
-use crate::attrs::{DisplayAttr, NestedAttr};
-use syn::Ident;
+use crate::attrs::{
+    DisplayAttr, FromStrAttr, NestedAttr, ParAttr, RandAttr, SchemaBound, SerdeMode, WasmAttr,
+};
 use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Path, Token};
 
 impl NestedAttr {
     /// attempts to parse the attribute from the given metadata
     pub fn parse_nested(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
+        // #[wrap(atomic)]
+        if meta.path.is_ident("atomic") {
+            return Ok(Self::Atomic);
+        }
+        // #[wrap(from_str)] or #[wrap(from_str(...))]
+        if meta.path.is_ident("from_str") {
+            return FromStrAttr::parse_nested(meta).map(Self::FromStr);
+        }
         // #[wrap(inner(...))]
         if meta.path.is_ident("inner") {
             let attr = DisplayAttr::parse_nested(meta)?;
             return Ok(Self::Inner(attr));
         }
+        // #[wrap(par)] or #[wrap(par(...))]
+        if meta.path.is_ident("par") {
+            return ParAttr::parse_nested(meta).map(Self::Par);
+        }
+        // #[wrap(rand)] or #[wrap(rand(...))]
+        if meta.path.is_ident("rand") {
+            return RandAttr::parse_nested(meta).map(Self::Rand);
+        }
+        // #[wrap(schema)] or #[wrap(schema(...))]
+        if meta.path.is_ident("schema") {
+            return SchemaBound::parse_nested(meta).map(Self::Schema);
+        }
+        // #[wrap(serde = "...")]
+        if meta.path.is_ident("serde") {
+            let lit = meta.value()?.parse::<LitStr>()?;
+            return SerdeMode::from_lit(&lit).map(Self::Serde);
+        }
+        // #[wrap(validate = "...")]
+        if meta.path.is_ident("validate") {
+            let lit = meta.value()?.parse::<LitStr>()?;
+            return parse_validate(&lit).map(Self::Validate);
+        }
+        // #[wrap(wasm)] or #[wrap(wasm(...))]
+        if meta.path.is_ident("wasm") {
+            return WasmAttr::parse_nested(meta).map(Self::Wasm);
+        }
 
-        Err(meta.error("unrecognized repr"))
+        Err(meta.error("unrecognized attribute"))
     }
 }
 
 impl Parse for NestedAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident: Ident = input.parse()?;
-        if ident == "inner" {
+        if ident == "atomic" {
+            Ok(NestedAttr::Atomic)
+        } else if ident == "from_str" {
+            Ok(NestedAttr::FromStr(FromStrAttr::default()))
+        } else if ident == "par" {
+            Ok(NestedAttr::Par(ParAttr::default()))
+        } else if ident == "wasm" {
+            Ok(NestedAttr::Wasm(WasmAttr::default()))
+        } else if ident == "inner" {
             let content;
             syn::parenthesized!(content in input);
             // Parse an optional identifier
@@ -34,8 +78,21 @@ impl Parse for NestedAttr {
             };
 
             Ok(NestedAttr::Inner(DisplayAttr { format }))
+        } else if ident == "serde" {
+            input.parse::<Token![=]>()?;
+            let lit = input.parse::<LitStr>()?;
+            SerdeMode::from_lit(&lit).map(NestedAttr::Serde)
+        } else if ident == "validate" {
+            input.parse::<Token![=]>()?;
+            let lit = input.parse::<LitStr>()?;
+            parse_validate(&lit).map(NestedAttr::Validate)
         } else {
             Err(syn::Error::new_spanned(ident, "unknown attribute"))
         }
     }
 }
+
+/// parses the path named by the literal, spanning the literal itself
+fn parse_validate(lit: &LitStr) -> syn::Result<Path> {
+    lit.parse::<Path>()
+}
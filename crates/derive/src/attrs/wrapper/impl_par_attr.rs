@@ -0,0 +1,32 @@
+/*
+    Appellation: impl_par_attr <module>
+    Created At: 2026.10.19:22:41:08
+    Contrib: @FL03
+*/
+use crate::attrs::ParAttr;
+use syn::meta::ParseNestedMeta;
+
+impl ParAttr {
+    /// attempts to parse the attribute, along with its optional parameters, from the given
+    /// metadata
+    pub fn parse_nested(meta: &ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        // #[wrap(par)]
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(attr);
+        }
+        // #[wrap(par(sum, product))]
+        meta.parse_nested_meta(|param| {
+            if param.path.is_ident("sum") {
+                attr.sum = true;
+                return Ok(());
+            }
+            if param.path.is_ident("product") {
+                attr.product = true;
+                return Ok(());
+            }
+            Err(param.error("unrecognized parameter"))
+        })?;
+        Ok(attr)
+    }
+}
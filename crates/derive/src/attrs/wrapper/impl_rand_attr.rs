@@ -0,0 +1,33 @@
+/*
+    Appellation: impl_rand_attr <module>
+    Created At: 2026.10.19:17:14:26
+    Contrib: @FL03
+*/
+use crate::attrs::RandAttr;
+use syn::ExprRange;
+use syn::meta::ParseNestedMeta;
+
+impl RandAttr {
+    /// attempts to parse the attribute, along with its optional parameters, from the given
+    /// metadata
+    pub fn parse_nested(meta: &ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        // #[wrap(rand)]
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(attr);
+        }
+        // #[wrap(rand(range = ...))]
+        meta.parse_nested_meta(|param| {
+            if param.path.is_ident("range") {
+                let range = param.value()?.parse::<ExprRange>()?;
+                if range.start.is_none() || range.end.is_none() {
+                    return Err(syn::Error::new_spanned(range, "expected a bounded range"));
+                }
+                attr.range = Some(range);
+                return Ok(());
+            }
+            Err(param.error("unrecognized parameter"))
+        })?;
+        Ok(attr)
+    }
+}
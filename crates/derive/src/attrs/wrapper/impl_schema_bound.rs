@@ -0,0 +1,65 @@
+/*
+    Appellation: impl_schema_bound <module>
+    Created At: 2026.10.19:16:21:40
+    Contrib: @FL03
+*/
+use crate::attrs::SchemaBound;
+use syn::meta::ParseNestedMeta;
+use syn::{Expr, ExprLit, ExprUnary, UnOp};
+
+impl SchemaBound {
+    /// the validation keywords recognized by the attribute along with their schema names
+    const KEYWORDS: [(&'static str, &'static str); 10] = [
+        ("minimum", "minimum"),
+        ("maximum", "maximum"),
+        ("exclusive_minimum", "exclusiveMinimum"),
+        ("exclusive_maximum", "exclusiveMaximum"),
+        ("multiple_of", "multipleOf"),
+        ("min_length", "minLength"),
+        ("max_length", "maxLength"),
+        ("pattern", "pattern"),
+        ("min_items", "minItems"),
+        ("max_items", "maxItems"),
+    ];
+
+    /// attempts to parse the, optional, parenthesized list of bounds from the given metadata
+    pub fn parse_nested(meta: &ParseNestedMeta<'_>) -> syn::Result<Vec<Self>> {
+        let mut bounds = Vec::new();
+        // #[wrap(schema)]
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(bounds);
+        }
+        // #[wrap(schema(...))]
+        meta.parse_nested_meta(|bound| {
+            let Some(keyword) = Self::KEYWORDS
+                .iter()
+                .find_map(|(name, keyword)| bound.path.is_ident(name).then_some(*keyword))
+            else {
+                return Err(bound.error("unrecognized schema keyword"));
+            };
+            let value = bound.value()?.parse::<Expr>()?;
+            if !is_literal(&value) {
+                return Err(syn::Error::new_spanned(value, "expected a literal"));
+            }
+            bounds.push(Self {
+                keyword: keyword.to_string(),
+                value,
+            });
+            Ok(())
+        })?;
+        Ok(bounds)
+    }
+}
+
+/// returns true if the expression is a, possibly negated, literal
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit { .. }) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
@@ -0,0 +1,30 @@
+/*
+    Appellation: impl_serde_mode <module>
+    Created At: 2026.10.19:15:31:18
+    Contrib: @FL03
+*/
+use crate::attrs::SerdeMode;
+use syn::LitStr;
+
+impl SerdeMode {
+    /// returns the name of the module within `contained::serde` implementing the mode
+    pub const fn module(&self) -> &'static str {
+        match self {
+            Self::Transparent => "transparent",
+            Self::Tagged => "tagged",
+            Self::Struct => "structured",
+        }
+    }
+    /// attempts to parse the mode from the given string literal
+    pub fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "transparent" => Ok(Self::Transparent),
+            "tagged" => Ok(Self::Tagged),
+            "struct" => Ok(Self::Struct),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "expected one of `transparent`, `tagged` or `struct`",
+            )),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/*
+    Appellation: impl_wasm_attr <module>
+    Created At: 2026.10.19:22:58:31
+    Contrib: @FL03
+*/
+use crate::attrs::WasmAttr;
+use syn::meta::ParseNestedMeta;
+
+impl WasmAttr {
+    /// attempts to parse the attribute, along with its optional parameters, from the given
+    /// metadata
+    pub fn parse_nested(meta: &ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut attr = Self::default();
+        // #[wrap(wasm)]
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(attr);
+        }
+        // #[wrap(wasm(option))]
+        meta.parse_nested_meta(|param| {
+            if param.path.is_ident("option") {
+                attr.option = true;
+                return Ok(());
+            }
+            Err(param.error("unrecognized parameter"))
+        })?;
+        Ok(attr)
+    }
+}
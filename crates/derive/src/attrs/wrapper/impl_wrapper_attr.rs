@@ -3,8 +3,11 @@
     Created At: 2026.01.20:14:20:08
     Contrib: @FL03
 */
-use crate::attrs::{DisplayAttr, NestedAttr, WrapperAttr};
-use syn::Attribute;
+use crate::attrs::{
+    DisplayAttr, FromStrAttr, NestedAttr, ParAttr, RandAttr, SchemaBound, SerdeMode, WasmAttr,
+    WrapperAttr,
+};
+use syn::{Attribute, Path};
 
 impl WrapperAttr {
     const BASEPATH: &'static str = "wrap";
@@ -13,21 +16,56 @@ impl WrapperAttr {
         self.inner = Some(attr);
     }
 
+    pub fn set_atomic(&mut self, atomic: bool) {
+        self.atomic = atomic;
+    }
+
+    pub fn set_from_str(&mut self, attr: FromStrAttr) {
+        self.from_str = Some(attr);
+    }
+
+    pub fn set_par(&mut self, attr: ParAttr) {
+        self.par = Some(attr);
+    }
+
+    pub fn set_rand(&mut self, attr: RandAttr) {
+        self.rand = Some(attr);
+    }
+
+    pub fn set_schema(&mut self, bounds: Vec<SchemaBound>) {
+        self.schema = Some(bounds);
+    }
+
+    pub fn set_serde(&mut self, mode: SerdeMode) {
+        self.serde = Some(mode);
+    }
+
+    pub fn set_validate(&mut self, path: Path) {
+        self.validate = Some(path);
+    }
+
+    pub fn set_wasm(&mut self, attr: WasmAttr) {
+        self.wasm = Some(attr);
+    }
+
     // tries to extract the root attribute from a list of attributes
     pub fn extract(attrs: &[Attribute]) -> syn::Result<Self> {
         let mut root = Self::default();
         for attr in attrs {
             if attr.path().is_ident(Self::BASEPATH) {
                 attr.parse_nested_meta(|meta| {
-                    if let Ok(nested) = NestedAttr::parse_nested(&meta) {
-                        match nested {
-                            NestedAttr::Inner(inner) => {
-                                root.set_inner(inner);
-                                return Ok(());
-                            }
-                        }
+                    match NestedAttr::parse_nested(&meta)? {
+                        NestedAttr::Atomic => root.set_atomic(true),
+                        NestedAttr::FromStr(attr) => root.set_from_str(attr),
+                        NestedAttr::Inner(inner) => root.set_inner(inner),
+                        NestedAttr::Par(attr) => root.set_par(attr),
+                        NestedAttr::Rand(attr) => root.set_rand(attr),
+                        NestedAttr::Schema(bounds) => root.set_schema(bounds),
+                        NestedAttr::Serde(mode) => root.set_serde(mode),
+                        NestedAttr::Validate(path) => root.set_validate(path),
+                        NestedAttr::Wasm(attr) => root.set_wasm(attr),
                     }
-                    Err(meta.error("unrecognized attribute"))
+                    Ok(())
                 })?;
             }
         }
@@ -2,17 +2,24 @@
     appellation: wrapper <module>
     authors: @FL03
 */
+use crate::attrs::{FromStrAttr, ParAttr, RandAttr, SchemaBound, SerdeMode, WasmAttr, WrapperAttr};
 use quote::quote;
 use syn::{Data, DataStruct, DeriveInput, Field, Generics, Ident};
 
 pub fn impl_wrapper(input: &DeriveInput) -> proc_macro2::TokenStream {
     // deconstruct the input to get the struct name and generics
     let DeriveInput {
+        attrs,
         data,
         generics,
         ident: name,
         .. // ignore other fields
     } = input;
+    // parse the `wrap` attributes
+    let options = match WrapperAttr::extract(attrs) {
+        Ok(options) => options,
+        Err(err) => return err.to_compile_error(),
+    };
     // split the generics for implementation
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     // handle the case where the data is a struct
@@ -25,11 +32,107 @@ pub fn impl_wrapper(input: &DeriveInput) -> proc_macro2::TokenStream {
         let methods = fields
             .iter()
             .map(|field| _handle_field(field, generics, name));
+        // generate any of the optional implementations
+        let atomic = fields
+            .iter()
+            .filter(|_| options.atomic)
+            .map(|field| _impl_atomic_repr(field, generics, name));
+        // the serde adapters and parallel extensions of `contained` operate on implementors
+        // of `Newtype`
+        let newtype = fields
+            .iter()
+            .filter(|_| options.serde.is_some() || options.par.is_some())
+            .map(|field| _impl_newtype(field, generics, name));
+        // a checked constructor is only used when deserializing, parsing, sampling or
+        // collecting the wrapper
+        let validate = match &options.validate {
+            // the ABI conversions are infallible, leaving no way to reject invalid values
+            // received from JS
+            Some(path) if options.wasm.is_some() => {
+                return syn::Error::new_spanned(
+                    path,
+                    "`#[wrap(validate = \"...\")]` cannot be combined with `#[wrap(wasm)]`",
+                )
+                .to_compile_error();
+            }
+            Some(path)
+                if options.serde.is_none()
+                    && options.from_str.is_none()
+                    && options.rand.is_none()
+                    && options.par.is_none() =>
+            {
+                return syn::Error::new_spanned(
+                    path,
+                    "`#[wrap(validate = \"...\")]` requires `#[wrap(serde = \"...\")]`, `#[wrap(from_str)]`, `#[wrap(rand)]` or `#[wrap(par)]`",
+                )
+                .to_compile_error();
+            }
+            Some(path) => Some(path.clone()),
+            None => None,
+        };
+        // the schema describes the serialized representation of the wrapper
+        let schema = match (&options.serde, &options.schema) {
+            (None, Some(_)) => {
+                return syn::Error::new_spanned(
+                    name,
+                    "`#[wrap(schema)]` requires `#[wrap(serde = \"...\")]`",
+                )
+                .to_compile_error();
+            }
+            (Some(mode), Some(bounds)) => fields
+                .iter()
+                .map(|field| _impl_json_schema(field, generics, name, *mode, bounds))
+                .next(),
+            (_, None) => None,
+        };
+        let serde = fields.iter().filter_map(|field| {
+            options
+                .serde
+                .map(|mode| _impl_serde(field, generics, name, mode, validate.as_ref()))
+        });
+        let from_str = fields.iter().filter_map(|field| {
+            options
+                .from_str
+                .as_ref()
+                .map(|attr| _impl_from_str(field, generics, name, attr, validate.as_ref()))
+        });
+        let rand = fields.iter().filter_map(|field| {
+            options
+                .rand
+                .as_ref()
+                .map(|attr| _impl_rand(field, generics, name, attr, validate.as_ref()))
+        });
+        let par = fields.iter().filter_map(|field| {
+            options
+                .par
+                .map(|attr| _impl_par(field, generics, attrs, name, attr, validate.as_ref()))
+        });
+        let wasm = fields.iter().filter_map(|field| {
+            options
+                .wasm
+                .map(|attr| _impl_wasm(field, generics, name, attr))
+        });
         // inject generics to ensure the wrapper can be used with generic types
         return quote! {
             impl #impl_generics #name #ty_generics #where_clause {
                 #(#methods)*
             }
+
+            #(#atomic)*
+
+            #(#newtype)*
+
+            #(#serde)*
+
+            #schema
+
+            #(#from_str)*
+
+            #(#rand)*
+
+            #(#par)*
+
+            #(#wasm)*
         };
     }
 
@@ -52,18 +155,34 @@ fn _handle_field(
         Some(_) => _handle_named(field, generics, name),
         None => _handle_unnamed(field, generics, name),
     };
+    // the `map` and `view` methods re-parameterize the wrapper and are only available
+    // whenever the field is the sole generic parameter of the struct
+    let generic = _is_generic_field(field_type, generics).then(|| {
+        quote! {
+            /// consumes the current instance and returns a new one that captures the result of the
+            /// closure on the wrapped field
+            #[inline]
+            pub fn map<U, F>(self, f: F) -> #name<U>
+            where
+                F: FnOnce(#field_type) -> U,
+            {
+                #name::new(f(self.value()))
+            }
+            /// returns a new instance of the wrapper that contains a reference to the inner value
+            pub const fn view(&self) -> #name<&#field_type> {
+                #name::new(self.get())
+            }
+            /// returns a new instance of the wrapper that contains a mutable reference to the
+            /// inner value
+            pub const fn view_mut(&mut self) -> #name<&mut #field_type> {
+                #name::new(self.get_mut())
+            }
+        }
+    });
     // generate the code for the wrapper methods
     quote! {
         #methods
-        /// consumes the current instance and returns a new one that captures the result of the
-        /// closure on the wrapped field
-        #[inline]
-        pub fn map<U, F>(self, f: F) -> #name<U>
-        where
-            F: FnOnce(#field_type) -> U,
-        {
-            #name::new(f(self.value()))
-        }
+        #generic
         /// [`replace`](core::mem::replace) the wrapped field with a new value and return
         /// the old value
         pub const fn replace(&mut self, value: #field_type) -> #field_type {
@@ -88,15 +207,17 @@ fn _handle_field(
         {
             ::core::mem::take(self.get_mut())
         }
-        /// returns a new instance of the wrapper that contains a reference to the inner value
-        pub const fn view(&self) -> #name<&#field_type> {
-            #name::new(self.get())
-        }
-        /// returns a new instance of the wrapper that contains a mutable reference to the
-        /// inner value
-        pub const fn view_mut(&mut self) -> #name<&mut #field_type> {
-            #name::new(self.get_mut())
+    }
+}
+
+/// returns true if the given type is the only type parameter declared by the generics
+fn _is_generic_field(ty: &syn::Type, generics: &Generics) -> bool {
+    let mut params = generics.type_params();
+    match (params.next(), params.next(), ty) {
+        (Some(param), None, syn::Type::Path(path)) => {
+            path.qself.is_none() && path.path.is_ident(&param.ident)
         }
+        _ => false,
     }
 }
 
@@ -164,6 +285,523 @@ fn _handle_unnamed(
     }
 }
 
+fn _impl_atomic_repr(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let Field {
+        ident: field_name,
+        ty: field_type,
+        ..
+    } = field;
+    // require the field to be representable before forwarding to it
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: ::contained::traits::AtomicRepr));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // handle both named and unnamed fields
+    let (access, ctor) = match field_name {
+        Some(f) => (quote!(self.#f), quote!(Self { #f: value })),
+        None => (quote!(self.0), quote!(Self(value))),
+    };
+    quote! {
+        impl #impl_generics ::contained::traits::AtomicRepr for #name #ty_generics #where_clause {
+            type Repr = <#field_type as ::contained::traits::AtomicRepr>::Repr;
+
+            fn into_repr(self) -> Self::Repr {
+                ::contained::traits::AtomicRepr::into_repr(#access)
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                let value = <#field_type as ::contained::traits::AtomicRepr>::from_repr(repr);
+                #ctor
+            }
+        }
+    }
+}
+
+fn _impl_newtype(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let Field {
+        ident: field_name,
+        ty: field_type,
+        ..
+    } = field;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name_str = name.to_string();
+    // handle both named and unnamed fields; unnamed fields use the default field name
+    let (access, ctor, field_str) = match field_name {
+        Some(f) => {
+            let field_str = f.to_string();
+            (
+                quote!(self.#f),
+                quote!(Self { #f: inner }),
+                Some(quote!(const FIELD: &'static str = #field_str;)),
+            )
+        }
+        None => (quote!(self.0), quote!(Self(inner)), None),
+    };
+    quote! {
+        impl #impl_generics ::contained::traits::Newtype for #name #ty_generics #where_clause {
+            type Inner = #field_type;
+
+            const NAME: &'static str = #name_str;
+            #field_str
+
+            fn from_inner(inner: #field_type) -> Self {
+                #ctor
+            }
+
+            fn as_inner(&self) -> &#field_type {
+                &#access
+            }
+
+            fn into_inner(self) -> #field_type {
+                #access
+            }
+        }
+    }
+}
+
+fn _impl_serde(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+    mode: SerdeMode,
+    validate: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let module = syn::Ident::new(mode.module(), proc_macro2::Span::call_site());
+    let (_, ty_generics, _) = generics.split_for_impl();
+    // require the field to be serializable before forwarding to it
+    let mut ser_generics = generics.clone();
+    ser_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: ::contained::serde::Serialize));
+    let (ser_impl_generics, _, ser_where_clause) = ser_generics.split_for_impl();
+    // introduce the lifetime of the deserializer
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    de_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: ::contained::serde::Deserialize<'de>));
+    let (de_impl_generics, _, de_where_clause) = de_generics.split_for_impl();
+    // deserialize through the checked constructor, if any
+    let deserialize = match validate {
+        Some(path) => quote! {
+            ::contained::serde::#module::deserialize_with(deserializer, #path)
+        },
+        None => quote! {
+            ::contained::serde::#module::deserialize(deserializer)
+        },
+    };
+    quote! {
+        impl #ser_impl_generics ::contained::serde::Serialize for #name #ty_generics #ser_where_clause {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::contained::serde::Serializer,
+            {
+                ::contained::serde::#module::serialize(self, serializer)
+            }
+        }
+
+        impl #de_impl_generics ::contained::serde::Deserialize<'de> for #name #ty_generics #de_where_clause {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: ::contained::serde::Deserializer<'de>,
+            {
+                #deserialize
+            }
+        }
+    }
+}
+
+fn _impl_json_schema(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+    mode: SerdeMode,
+    bounds: &[SchemaBound],
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let module = syn::Ident::new(mode.module(), proc_macro2::Span::call_site());
+    // require the field to describe its own schema
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: ::contained::json::JsonSchema));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let bounds = bounds
+        .iter()
+        .map(|SchemaBound { keyword, value }| quote!((#keyword, ::contained::json::json!(#value))));
+    quote! {
+        impl #impl_generics ::contained::json::JsonSchema for #name #ty_generics #where_clause {
+            fn json_schema() -> ::contained::json::Value {
+                let inner = ::contained::json::schema::bound(
+                    <#field_type as ::contained::json::JsonSchema>::json_schema(),
+                    [#(#bounds),*],
+                );
+                ::contained::json::schema::#module::<Self>(inner)
+            }
+        }
+    }
+}
+
+fn _impl_from_str(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+    attr: &FromStrAttr,
+    validate: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let name_str = name.to_string();
+    // require the field to be parsable into an error convertible into `contained::Error`
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(syn::parse_quote!(#field_type: ::core::str::FromStr));
+    where_clause.predicates.push(syn::parse_quote!(
+        ::contained::Error: ::core::convert::From<<#field_type as ::core::str::FromStr>::Err>
+    ));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // strip the affixes, if any, before handing the input to the parser of the field
+    let strip = |affix: Option<&String>, kind: &str| {
+        affix.map(|affix| {
+            let method = syn::Ident::new(&format!("strip_{kind}"), proc_macro2::Span::call_site());
+            let reason = format!("expected the {kind} `{affix}`");
+            quote! {
+                let s = s
+                    .#method(#affix)
+                    .ok_or_else(|| ::contained::Error::parse(#name_str, #reason))?;
+            }
+        })
+    };
+    let prefix = strip(attr.prefix.as_ref(), "prefix");
+    let suffix = strip(attr.suffix.as_ref(), "suffix");
+    // the parsed value passes through the checked constructor, if any
+    let ctor = match validate {
+        Some(path) => quote! {
+            #path(inner).map_err(|err| ::contained::Error::rejected(#name_str, err))
+        },
+        None => quote!(::core::result::Result::Ok(Self::new(inner))),
+    };
+    quote! {
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::contained::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #prefix
+                #suffix
+                let inner = <#field_type as ::core::str::FromStr>::from_str(s)?;
+                #ctor
+            }
+        }
+    }
+}
+
+fn _impl_rand(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+    attr: &RandAttr,
+    validate: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // every sample passes through the checked constructor, if any
+    let ctor = match validate {
+        Some(path) => quote!(#path),
+        None => quote! {
+            |inner| ::core::result::Result::<Self, ::core::convert::Infallible>::Ok(Self::new(inner))
+        },
+    };
+    // sample the inner value from the given range, or the standard distribution otherwise
+    let (bound, sample) = match &attr.range {
+        Some(range) => (
+            quote!(#field_type: ::contained::rand::SampleUniform),
+            quote!(::contained::rand::RngExt::random_range(rng, #range)),
+        ),
+        None => (
+            quote!(::contained::rand::StandardUniform: ::contained::rand::Distribution<#field_type>),
+            quote!(<Self as ::contained::rand::Distribution<#field_type>>::sample(self, rng)),
+        ),
+    };
+    let mut dist_generics = generics.clone();
+    dist_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#bound));
+    let (dist_impl_generics, _, dist_where_clause) = dist_generics.split_for_impl();
+    quote! {
+        impl #dist_impl_generics ::contained::rand::Distribution<#name #ty_generics> for ::contained::rand::StandardUniform #dist_where_clause {
+            fn sample<__R>(&self, rng: &mut __R) -> #name #ty_generics
+            where
+                __R: ::contained::rand::Rng + ?Sized,
+            {
+                <#name #ty_generics>::__sample_with(|| #sample)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// returns a random instance sampled from the [`StandardUniform`](::contained::rand::StandardUniform)
+            /// distribution using a generator seeded by the system
+            pub fn random() -> Self
+            where
+                ::contained::rand::StandardUniform: ::contained::rand::Distribution<Self>,
+            {
+                Self::random_with(&mut ::contained::rand::rng())
+            }
+            /// returns a random instance sampled from the [`StandardUniform`](::contained::rand::StandardUniform)
+            /// distribution using the given generator
+            pub fn random_with<__R>(rng: &mut __R) -> Self
+            where
+                __R: ::contained::rand::Rng + ?Sized,
+                ::contained::rand::StandardUniform: ::contained::rand::Distribution<Self>,
+            {
+                ::contained::rand::Distribution::sample(&::contained::rand::StandardUniform, rng)
+            }
+            #[doc(hidden)]
+            pub fn __sample_with<__F>(sample: __F) -> Self
+            where
+                __F: FnMut() -> #field_type,
+            {
+                ::contained::rand::sample_valid(sample, #ctor)
+            }
+            /// returns an instance whose inner value is uniformly sampled from the given range
+            pub fn random_range<__Rg>(range: __Rg) -> Self
+            where
+                #field_type: ::contained::rand::SampleUniform,
+                __Rg: ::contained::rand::SampleRange<#field_type> + Clone,
+            {
+                let mut rng = ::contained::rand::rng();
+                Self::__sample_with(|| ::contained::rand::RngExt::random_range(&mut rng, range.clone()))
+            }
+            /// returns an instance whose inner value is sampled from the given distribution
+            pub fn sample_from<__D>(dist: __D) -> Self
+            where
+                __D: ::contained::rand::Distribution<#field_type>,
+            {
+                let mut rng = ::contained::rand::rng();
+                Self::__sample_with(|| dist.sample(&mut rng))
+            }
+        }
+    }
+}
+
+fn _impl_par(
+    field: &Field,
+    generics: &Generics,
+    attrs: &[syn::Attribute],
+    name: &syn::Ident,
+    attr: ParAttr,
+    validate: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // values produced by the parallel iterators pass through the checked constructor, if any
+    let ctor = _panicking_ctor(name, validate);
+    // only wrappers sharing the layout of their field, without validation, are transparent
+    let repr_transparent = attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|repr| repr == "transparent")
+    });
+    let transparent = (repr_transparent && validate.is_none()).then(|| {
+        quote! {
+            unsafe impl #impl_generics ::contained::traits::Transparent for #name #ty_generics #where_clause {}
+        }
+    });
+    // extending the field in-place would bypass the checked constructor
+    let extend = validate.is_none().then(|| {
+        let mut generics = generics.clone();
+        generics.params.push(syn::parse_quote!(__A: Send));
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_type: ::contained::par::ParallelExtend<__A>));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::contained::par::ParallelExtend<__A> for #name #ty_generics #where_clause {
+                fn par_extend<__I>(&mut self, par_iter: __I)
+                where
+                    __I: ::contained::par::IntoParallelIterator<Item = __A>,
+                {
+                    ::contained::par::ParallelExtend::par_extend(self.get_mut(), par_iter)
+                }
+            }
+        }
+    });
+    // returns the generics extended by the given parameter and predicate; lifetimes are
+    // inserted ahead of the other parameters
+    let extend_generics = |param: Option<syn::GenericParam>, predicate: syn::WherePredicate| {
+        let mut generics = generics.clone();
+        match param {
+            Some(param @ syn::GenericParam::Lifetime(_)) => generics.params.insert(0, param),
+            Some(param) => generics.params.push(param),
+            None => {}
+        }
+        generics.make_where_clause().predicates.push(predicate);
+        generics
+    };
+    let from_generics = extend_generics(
+        Some(syn::parse_quote!(__A: Send)),
+        syn::parse_quote!(#field_type: ::contained::par::FromParallelIterator<__A>),
+    );
+    let (from_impl_generics, _, from_where_clause) = from_generics.split_for_impl();
+    // the `Sum` and `Product` implementations required by `ParallelIterator::{sum, product}`
+    // are opt-in, since they would conflict with those generated for the arithmetic operators
+    let folds = [
+        (attr.sum, quote!(::core::iter::Sum), quote!(sum)),
+        (attr.product, quote!(::core::iter::Product), quote!(product)),
+    ]
+    .into_iter()
+    .filter(|(requested, ..)| *requested)
+    .map(|(_, fold, call)| {
+        let owned = extend_generics(None, syn::parse_quote!(#field_type: #fold));
+        let (owned_impl_generics, _, owned_where_clause) = owned.split_for_impl();
+        let borrowed = extend_generics(
+            Some(syn::parse_quote!('__a)),
+            syn::parse_quote!(#field_type: #fold<&'__a #field_type>),
+        );
+        let (borrowed_impl_generics, _, borrowed_where_clause) = borrowed.split_for_impl();
+        quote! {
+            impl #owned_impl_generics #fold for #name #ty_generics #owned_where_clause {
+                fn #call<__I>(iter: __I) -> Self
+                where
+                    __I: Iterator<Item = Self>,
+                {
+                    let inner = #fold::#call(iter.map(|item| item.value()));
+                    (#ctor)(inner)
+                }
+            }
+
+            impl #borrowed_impl_generics #fold<&'__a #name #ty_generics> for #name #ty_generics #borrowed_where_clause {
+                fn #call<__I>(iter: __I) -> Self
+                where
+                    __I: Iterator<Item = &'__a Self>,
+                {
+                    let inner = #fold::#call(iter.map(|item| item.get()));
+                    (#ctor)(inner)
+                }
+            }
+        }
+    });
+    quote! {
+        #transparent
+
+        impl #from_impl_generics ::contained::par::FromParallelIterator<__A> for #name #ty_generics #from_where_clause {
+            fn from_par_iter<__I>(par_iter: __I) -> Self
+            where
+                __I: ::contained::par::IntoParallelIterator<Item = __A>,
+            {
+                let inner = <#field_type as ::contained::par::FromParallelIterator<__A>>::from_par_iter(par_iter);
+                (#ctor)(inner)
+            }
+        }
+
+        #extend
+
+        #(#folds)*
+    }
+}
+
+fn _impl_wasm(
+    field: &Field,
+    generics: &Generics,
+    name: &syn::Ident,
+    attr: WasmAttr,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    // returns the generics extended by the given predicate
+    let bounded = |predicate: syn::WherePredicate| {
+        let mut generics = generics.clone();
+        generics.make_where_clause().predicates.push(predicate);
+        generics
+    };
+    let describe = bounded(syn::parse_quote!(#field_type: ::contained::wasm::WasmDescribe));
+    let (describe_impl_generics, _, describe_where_clause) = describe.split_for_impl();
+    let into = bounded(syn::parse_quote!(#field_type: ::contained::wasm::IntoWasmAbi));
+    let (into_impl_generics, _, into_where_clause) = into.split_for_impl();
+    let from = bounded(syn::parse_quote!(#field_type: ::contained::wasm::FromWasmAbi));
+    let (from_impl_generics, _, from_where_clause) = from.split_for_impl();
+    // the optional conversions are opt-in, since fields without a reserved `None` value, e.g.
+    // `u32`, only pass their optional values using a different ABI
+    let option = attr.option.then(|| {
+        let into = bounded(syn::parse_quote!(#field_type: ::contained::wasm::OptionIntoWasmAbi));
+        let (into_impl_generics, _, into_where_clause) = into.split_for_impl();
+        let from = bounded(syn::parse_quote!(#field_type: ::contained::wasm::OptionFromWasmAbi));
+        let (from_impl_generics, _, from_where_clause) = from.split_for_impl();
+        quote! {
+            impl #into_impl_generics ::contained::wasm::OptionIntoWasmAbi for #name #ty_generics #into_where_clause {
+                fn none() -> Self::Abi {
+                    <#field_type as ::contained::wasm::OptionIntoWasmAbi>::none()
+                }
+            }
+
+            impl #from_impl_generics ::contained::wasm::OptionFromWasmAbi for #name #ty_generics #from_where_clause {
+                fn is_none(abi: &Self::Abi) -> bool {
+                    <#field_type as ::contained::wasm::OptionFromWasmAbi>::is_none(abi)
+                }
+            }
+        }
+    });
+    quote! {
+        impl #describe_impl_generics ::contained::wasm::WasmDescribe for #name #ty_generics #describe_where_clause {
+            fn describe() {
+                <#field_type as ::contained::wasm::WasmDescribe>::describe()
+            }
+        }
+
+        impl #into_impl_generics ::contained::wasm::IntoWasmAbi for #name #ty_generics #into_where_clause {
+            type Abi = <#field_type as ::contained::wasm::IntoWasmAbi>::Abi;
+
+            fn into_abi(self) -> Self::Abi {
+                <#field_type as ::contained::wasm::IntoWasmAbi>::into_abi(self.value())
+            }
+        }
+
+        impl #from_impl_generics ::contained::wasm::FromWasmAbi for #name #ty_generics #from_where_clause {
+            type Abi = <#field_type as ::contained::wasm::FromWasmAbi>::Abi;
+
+            unsafe fn from_abi(js: Self::Abi) -> Self {
+                let inner = unsafe { <#field_type as ::contained::wasm::FromWasmAbi>::from_abi(js) };
+                Self::new(inner)
+            }
+        }
+
+        #option
+    }
+}
+
+/// returns a constructor passing the inner value through the checked constructor, if any,
+/// panicking whenever the value is invalid
+fn _panicking_ctor(name: &syn::Ident, validate: Option<&syn::Path>) -> proc_macro2::TokenStream {
+    match validate {
+        Some(path) => quote! {
+            |inner| match #path(inner) {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!(::core::concat!("the value is not a valid `", ::core::stringify!(#name), "`"))
+                }
+            }
+        },
+        None => quote!(Self::new),
+    }
+}
+
 fn _convert_generic_where_clause(
     new_ident: &Ident,
     clause: &syn::WhereClause,
@@ -23,6 +23,41 @@ use syn::{DeriveInput, parse_macro_input};
 
 /// The [`Wrapper`] macro is designed for single-field structs, implementing additional methods
 /// supporting interactions with the inner value
+///
+/// ## Attributes
+///
+/// - `#[wrap(atomic)]`: implements `contained::traits::AtomicRepr` by forwarding to the field,
+///   allowing integer-backed wrappers to be stored within a `contained::wrappers::Atomic`
+/// - `#[wrap(serde = "transparent" | "tagged" | "struct")]`: implements `Serialize` and
+///   `Deserialize` using the corresponding adapter within `contained::serde`, representing the
+///   wrapper as its inner value, as `{"Name": value}` or as `{"field": value}`, respectively;
+///   this also implements `contained::traits::Newtype`, the interface used by the adapters
+/// - `#[wrap(from_str)]` or `#[wrap(from_str(prefix = "...", suffix = "..."))]`: implements
+///   `FromStr` by forwarding to the parser of the field, stripping the given affixes, if any,
+///   beforehand, e.g. `"42ms"` for `Millis(42)`; errors are reported as a `contained::Error`,
+///   requiring it to implement `From` for the error of the field
+/// - `#[wrap(validate = "path")]`: deserializes, parses, samples or collects the inner value
+///   through the given checked constructor, i.e. `fn(Inner) -> Result<Self, E>` where
+///   `E: Display`; requires `serde`, `from_str`, `rand` or `par` and cannot be combined with
+///   `wasm`, whose conversions are unable to reject invalid values
+/// - `#[wrap(par)]` or `#[wrap(par(sum, product))]`: implements `contained::traits::Newtype`,
+///   along with `FromParallelIterator` and `ParallelExtend` whenever the field supports them;
+///   `sum` and `product` additionally implement `Sum` and `Product` for both owned and
+///   borrowed wrappers, as required by `ParallelIterator::{sum, product}`, and are omitted
+///   when the folds are provided elsewhere, e.g. by `binary_wrapper!`; `#[repr(transparent)]`
+///   wrappers also implement `contained::traits::Transparent`
+/// - `#[wrap(rand)]` or `#[wrap(rand(range = lo..hi))]`: implements
+///   `Distribution<Self>` for `StandardUniform`, sampling the field from the standard
+///   distribution or the given range, along with the `random`, `random_with`, `random_range`
+///   and `sample_from` constructors; validated wrappers only ever sample valid values
+/// - `#[wrap(schema(minimum = 0, max_length = 8, ...))]`: implements
+///   `contained::json::JsonSchema`, extending the schema of the field with the given validation
+///   keywords before composing it with the `serde` representation; requires `serde`
+/// - `#[wrap(wasm)]` or `#[wrap(wasm(option))]`: implements the `wasm-bindgen` ABI
+///   conversions, i.e. `WasmDescribe`, `IntoWasmAbi` and `FromWasmAbi`, by forwarding to the
+///   field, passing the wrapper across the JS boundary as its inner value; `option`
+///   additionally implements `OptionIntoWasmAbi` and `OptionFromWasmAbi`, allowing the
+///   wrapper to be passed as an `Option`, and requires the field to support them as well
 #[proc_macro_derive(Wrapper, attributes(wrap))]
 pub fn wrapper(input: TokenStream) -> TokenStream {
     // Parse the inputs into the proper struct
@@ -23,3 +23,31 @@ fn test_derive_struct_wrapper() {
     b.set(<isize>::MAX);
     assert_eq!(b.get(), &<isize>::MAX);
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Wrapper)]
+pub struct Id(u32);
+
+#[derive(Clone, Debug, PartialEq, Wrapper)]
+pub struct Items<T>(Vec<T>);
+
+#[test]
+fn test_derive_non_generic_field_wrapper() {
+    // the `map` and `view` methods are omitted since the field is not the type parameter
+    let mut id = Id::new(1);
+    id.set(2);
+    assert_eq!(id.replace(3), 2);
+    assert_eq!(id.value(), 3);
+
+    let mut items = Items::new(vec![1, 2]);
+    items.get_mut().push(3);
+    assert_eq!(items.take(), vec![1, 2, 3]);
+    assert!(items.get().is_empty());
+}
+
+#[test]
+fn test_derive_generic_field_views() {
+    let mut a = A::new(1_u8);
+    *a.view_mut().value() += 1;
+    assert_eq!(a.view(), A::new(&2));
+    assert_eq!(a.map(u32::from), A::new(2_u32));
+}
@@ -0,0 +1,101 @@
+/*
+    appellation: collection_ast <module>
+    authors: @FL03
+*/
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+/// The groups of implementations recognized by the `collection_wrapper` macro, e.g. `iter`,
+/// `index` or `all`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CollectionGroup {
+    /// `IntoIterator` for owned and borrowed wrappers, along with `iter` and `iter_mut`
+    Iter,
+    /// the `len` and `is_empty` methods
+    Len,
+    /// the `len`, `is_empty` and `chars` methods of string slices
+    Text,
+    /// `FromIterator` and `Extend`
+    Collect,
+    /// `Index` and `IndexMut`
+    Index,
+    /// every group listed above, except for `Text`
+    All,
+}
+
+/// The set of implementations to generate, resolved from a list of [`CollectionGroup`]s
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CollectionSet {
+    pub iter: bool,
+    pub len: bool,
+    pub text: bool,
+    pub collect: bool,
+    pub index: bool,
+}
+
+impl CollectionGroup {
+    /// the names of each group, used within errors
+    pub const NAMES: &'static str = "`iter`, `len`, `text`, `collect`, `index` or `all`";
+}
+
+impl CollectionSet {
+    /// returns a set containing every implementation, except for those of `text` which
+    /// overlap with `len`
+    pub const fn all() -> Self {
+        Self {
+            iter: true,
+            len: true,
+            text: false,
+            collect: true,
+            index: true,
+        }
+    }
+    /// add the implementations of the given group to the set
+    pub fn insert(&mut self, group: CollectionGroup) {
+        match group {
+            CollectionGroup::Iter => self.iter = true,
+            CollectionGroup::Len => self.len = true,
+            CollectionGroup::Text => self.text = true,
+            CollectionGroup::Collect => self.collect = true,
+            CollectionGroup::Index => self.index = true,
+            CollectionGroup::All => {
+                *self = Self {
+                    text: self.text,
+                    ..Self::all()
+                }
+            }
+        }
+    }
+}
+
+impl FromIterator<CollectionGroup> for CollectionSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = CollectionGroup>,
+    {
+        let mut set = Self::default();
+        iter.into_iter().for_each(|group| set.insert(group));
+        set
+    }
+}
+
+impl Parse for CollectionGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        let group = match ident.to_string().as_str() {
+            "iter" => Self::Iter,
+            "len" => Self::Len,
+            "text" => Self::Text,
+            "collect" => Self::Collect,
+            "index" => Self::Index,
+            "all" => Self::All,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("expected one of {}", Self::NAMES),
+                ));
+            }
+        };
+        Ok(group)
+    }
+}
@@ -0,0 +1,101 @@
+/*
+    appellation: ops_ast <module>
+    authors: @FL03
+*/
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+/// The groups of operations recognized by the `ops_wrapper` macro and the `WrapperOps` derive,
+/// e.g. `arith`, `bits` or `all`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum OpsGroup {
+    /// `Add`, `Sub`, `Mul`, `Div` and `Rem`
+    Arith,
+    /// `BitAnd`, `BitOr` and `BitXor`
+    Bits,
+    /// `Shl` and `Shr`
+    Shifts,
+    /// `Neg`
+    Neg,
+    /// `Not`
+    Not,
+    /// the assign counterparts of the selected binary operations
+    Assign,
+    /// every group listed above
+    All,
+}
+
+/// The set of operations to implement, resolved from a list of [`OpsGroup`]s
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OpsSet {
+    pub arith: bool,
+    pub bits: bool,
+    pub shifts: bool,
+    pub neg: bool,
+    pub not: bool,
+    pub assign: bool,
+}
+
+impl OpsGroup {
+    /// the names of each group, used within errors
+    pub const NAMES: &'static str = "`arith`, `bits`, `shifts`, `neg`, `not`, `assign` or `all`";
+}
+
+impl OpsSet {
+    /// returns a set containing every operation
+    pub const fn all() -> Self {
+        Self {
+            arith: true,
+            bits: true,
+            shifts: true,
+            neg: true,
+            not: true,
+            assign: true,
+        }
+    }
+    /// add the operations of the given group to the set
+    pub fn insert(&mut self, group: OpsGroup) {
+        match group {
+            OpsGroup::Arith => self.arith = true,
+            OpsGroup::Bits => self.bits = true,
+            OpsGroup::Shifts => self.shifts = true,
+            OpsGroup::Neg => self.neg = true,
+            OpsGroup::Not => self.not = true,
+            OpsGroup::Assign => self.assign = true,
+            OpsGroup::All => *self = Self::all(),
+        }
+    }
+}
+
+impl FromIterator<OpsGroup> for OpsSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = OpsGroup>,
+    {
+        let mut set = Self::default();
+        iter.into_iter().for_each(|group| set.insert(group));
+        set
+    }
+}
+
+impl Parse for OpsGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        let group = match ident.to_string().as_str() {
+            "arith" => Self::Arith,
+            "bits" => Self::Bits,
+            "shifts" => Self::Shifts,
+            "neg" => Self::Neg,
+            "not" => Self::Not,
+            "assign" => Self::Assign,
+            "all" => Self::All,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("expected one of {}", Self::NAMES),
+                ));
+            }
+        };
+        Ok(group)
+    }
+}
@@ -3,10 +3,21 @@
     authors: @FL03
 */
 
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::Impl;
-use syn::{AngleBracketedGenericArguments, Ident, Token, WhereClause, braced};
+use syn::{
+    AngleBracketedGenericArguments, GenericArgument, Ident, Member, Path, Token, Type, WhereClause,
+    WherePredicate, braced, parenthesized,
+};
+
+pub(crate) mod kw {
+    syn::custom_keyword!(rhs);
+    syn::custom_keyword!(scalar);
+    syn::custom_keyword!(with);
+}
 
 fn _parse_ops(input: ParseStream) -> syn::Result<Punctuated<MethodCallAst, Token![,]>> {
     // parse the operations defined within braces
@@ -17,28 +28,237 @@ fn _parse_ops(input: ParseStream) -> syn::Result<Punctuated<MethodCallAst, Token
 
 #[allow(dead_code)]
 /// The abstract syntax tree for the `binary_wrapper` macro input;
-/// e.g. `impl A { Add.add, Sub.sub }` or `impl B.field { Add.add, Sub.sub }`
-pub struct WrapperImpls {
+/// e.g. `impl A { Add.add, Sub.sub }` or `impl B.field { Add.add, Sub.sub }`. The entries of
+/// the block default to [`MethodCallAst`] but may be any parsable type. The target may list
+/// additional type parameters, with the wrapped type marked by an underscore, e.g.
+/// `impl Quantity<_, U>.value { ... }`, followed by the constructor of its values and the
+/// bounds of the wrapped type, e.g. `impl NotNan with NotNan::new where _: TotalFloat { ... }`;
+/// the wrapped type may also be named by the generics, e.g. `impl<T> A where T: Copy { ... }`.
+pub struct WrapperImpls<Op = MethodCallAst> {
     pub impl_token: Impl,
     pub generics: Option<AngleBracketedGenericArguments>,
     pub target: Ident,
+    pub args: Option<AngleBracketedGenericArguments>,
     pub field: Option<Ident>,
+    pub ctor: Option<Path>,
     pub where_clause: Option<WhereClause>,
-    pub ops: Punctuated<MethodCallAst, Token![,]>,
+    pub ops: Punctuated<Op, Token![,]>,
+    /// the bounds of the type parameter of a derived target, if any
+    pub bounds: Option<ParamBounds>,
+}
+
+/// The bounds placed upon the type parameter of a derived target, e.g. `T: Copy` for
+/// `struct Wrapper<T: Copy>(T)`, along with the predicates of its where clause; each of the
+/// types wrapped by the target within the generated implementations is subject to them.
+#[derive(Clone)]
+pub struct ParamBounds {
+    pub param: Ident,
+    pub predicates: Vec<WherePredicate>,
 }
 
 #[allow(dead_code)]
+/// A single operation, e.g. `Add.add`, optionally followed by a parenthesized list of
+/// operands, e.g. `PartialEq.eq(i32, u64)`, an output type, e.g. `Mul.mul(Meters) -> Area`,
+/// and an explicit assign operation, e.g. `my::Dot.dot => my::DotAssign.dot_assign`. The trait
+/// may be given as any path; a single identifier refers to the trait of the same name within
+/// `core::ops`. Prefixing the operands with the `scalar` keyword, e.g.
+/// `Mul.mul(scalar f32, f64)`, marks them as unwrapped scalar types, while `rhs scalar`
+/// restricts them to the right-hand side of the operation, e.g. `Div.div(rhs scalar f64)`.
 pub struct MethodCallAst {
-    pub name: Ident,
+    pub name: Path,
     pub dot: Token![.],
     pub call: Ident,
+    pub rhs: Option<kw::rhs>,
+    pub scalar: Option<kw::scalar>,
+    pub args: Option<Punctuated<OperandAst, Token![,]>>,
+    pub output: Option<OutputAst>,
+    pub assign: Option<AssignAst>,
+}
+
+#[allow(dead_code)]
+/// A type used as an operand, optionally followed by the field holding its inner value, e.g.
+/// `Meters` or `Meters.value`
+pub struct OperandAst {
+    pub ty: Type,
+    pub field: Option<(Token![.], Member)>,
+}
+
+#[allow(dead_code)]
+/// The output type of an operation, e.g. `-> SquareMeters.value`
+pub struct OutputAst {
+    pub arrow: Token![->],
+    pub operand: OperandAst,
+}
+
+#[allow(dead_code)]
+/// The explicit assign operation paired with an operation, e.g. `=> AddAssign.add_assign`
+pub struct AssignAst {
+    pub arrow: Token![=>],
+    pub name: Path,
+    pub dot: Token![.],
+    pub call: Ident,
+}
+
+/// resolves the given path, qualifying single identifiers with `::core::ops`
+fn qualify(path: &Path) -> TokenStream {
+    match path.get_ident() {
+        Some(ident) => quote!(::core::ops::#ident),
+        None => path.to_token_stream(),
+    }
+}
+
+impl MethodCallAst {
+    /// returns true if the operation refers to the given trait of `core::ops`
+    pub fn is_core_op(&self, name: &str) -> bool {
+        self.name.is_ident(name)
+    }
+    /// returns the fully qualified path of the trait
+    pub fn trait_path(&self) -> TokenStream {
+        qualify(&self.name)
+    }
+    /// returns the fully qualified path and method of the assign operation, if any; the
+    /// operators of `core::ops` default to their `*Assign` counterparts while other traits
+    /// must specify it explicitly
+    pub fn assign_op(&self) -> Option<(TokenStream, Ident)> {
+        if let Some(AssignAst { name, call, .. }) = &self.assign {
+            return Some((qualify(name), call.clone()));
+        }
+        let ident = self.name.get_ident()?;
+        let op_assign = format_ident!("{}Assign", ident);
+        let call_assign = format_ident!("{}_assign", self.call);
+        Some((quote!(::core::ops::#op_assign), call_assign))
+    }
+    /// returns an error if the operation was given any arguments
+    pub fn deny_args(&self) -> syn::Result<()> {
+        match &self.args {
+            Some(args) => Err(syn::Error::new_spanned(
+                args,
+                format!("`{}` does not accept any arguments", self.display()),
+            )),
+            None => Ok(()),
+        }
+    }
+    /// returns an error if the operands of the operation were marked as scalars
+    pub fn deny_scalar(&self) -> syn::Result<()> {
+        match &self.scalar {
+            Some(scalar) => Err(syn::Error::new_spanned(
+                scalar,
+                format!("`{}` does not accept scalar operands", self.display()),
+            )),
+            None => Ok(()),
+        }
+    }
+    /// returns an error if the operation was given an output type
+    pub fn deny_output(&self) -> syn::Result<()> {
+        match &self.output {
+            Some(output) => Err(syn::Error::new_spanned(
+                &output.operand.ty,
+                format!("`{}` does not accept an output type", self.display()),
+            )),
+            None => Ok(()),
+        }
+    }
+    /// returns an error if the operation was given an explicit assign operation
+    pub fn deny_assign(&self) -> syn::Result<()> {
+        match &self.assign {
+            Some(AssignAst { arrow, call, .. }) => Err(syn::Error::new_spanned(
+                quote!(#arrow #call),
+                format!("`{}` does not accept an assign operation", self.display()),
+            )),
+            None => Ok(()),
+        }
+    }
+    /// returns a human-readable representation of the operation used within errors
+    fn display(&self) -> String {
+        let name = self.name.to_token_stream().to_string().replace(' ', "");
+        format!("{name}.{}", self.call)
+    }
+}
+
+impl Parse for OperandAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        let field = if input.peek(Token![.]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+        Ok(Self { ty, field })
+    }
+}
+
+impl ToTokens for OperandAst {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ty.to_tokens(tokens);
+        if let Some((dot, member)) = &self.field {
+            dot.to_tokens(tokens);
+            member.to_tokens(tokens);
+        }
+    }
+}
+
+impl Parse for OutputAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            arrow: input.parse()?,
+            operand: input.parse()?,
+        })
+    }
+}
+
+impl Parse for AssignAst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            arrow: input.parse()?,
+            name: input.call(Path::parse_mod_style)?,
+            dot: input.parse()?,
+            call: input.parse()?,
+        })
+    }
 }
 
 impl Parse for MethodCallAst {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let name = input.parse::<Ident>()?;
+        let name = input.call(Path::parse_mod_style)?;
         let period = input.parse::<Token![.]>()?;
         let call = input.parse::<Ident>()?;
+        // parse the optional, parenthesized arguments, which may be marked as scalars
+        let (rhs, scalar, args) = if input.peek(syn::token::Paren) {
+            let content;
+            let _ = parenthesized! { content in input };
+            let rhs = if content.peek(kw::rhs) && content.peek2(kw::scalar) {
+                Some(content.parse::<kw::rhs>()?)
+            } else {
+                None
+            };
+            let scalar = if is_scalar(&content) {
+                Some(content.parse::<kw::scalar>()?)
+            } else {
+                None
+            };
+            let args = Punctuated::<OperandAst, Token![,]>::parse_terminated(&content)?;
+            if let (Some(scalar), true) = (&scalar, args.is_empty()) {
+                return Err(syn::Error::new_spanned(
+                    scalar,
+                    "expected at least one scalar type",
+                ));
+            }
+            (rhs, scalar, Some(args))
+        } else {
+            (None, None, None)
+        };
+        // parse the optional output type
+        let output = if input.peek(Token![->]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        // parse the optional assign operation
+        let assign = if input.peek(Token![=>]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
         if input.peek(Token![,]) {
             input.parse::<Token![,]>()?;
         }
@@ -46,11 +266,29 @@ impl Parse for MethodCallAst {
             name,
             dot: period,
             call,
+            rhs,
+            scalar,
+            args,
+            output,
+            assign,
         })
     }
 }
 
-impl Parse for WrapperImpls {
+/// returns true if the stream begins with the `scalar` keyword followed by a type, rather than
+/// a wrapper type which happens to be named `scalar`
+fn is_scalar(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<kw::scalar>().is_ok()
+        && !fork.is_empty()
+        && !fork.peek(Token![,])
+        && !fork.peek(Token![.])
+}
+
+impl<Op> Parse for WrapperImpls<Op>
+where
+    Op: Parse,
+{
     fn parse(input: ParseStream) -> syn::Result<Self> {
         // parse the `impl` keyword
         let impl_token = input.parse::<Impl>()?;
@@ -61,6 +299,14 @@ impl Parse for WrapperImpls {
             None
         };
         let target = input.parse::<Ident>()?;
+        // parse the optional parameters of the target, e.g. `<_, U>`
+        let args = if input.peek(Token![<]) {
+            let args = input.parse::<AngleBracketedGenericArguments>()?;
+            validate_args(&args)?;
+            Some(args)
+        } else {
+            None
+        };
         // resolve the optional named field
         let field = if input.peek(Token![.]) {
             input.parse::<Token![.]>()?;
@@ -68,18 +314,30 @@ impl Parse for WrapperImpls {
         } else {
             None
         };
-        // parse the optional where clause
-        let where_clause = if input.peek(Token![where]) {
+        // parse the optional constructor, e.g. `with NotNan::new`
+        let ctor = if input.peek(kw::with) {
+            input.parse::<kw::with>()?;
             Some(input.parse()?)
         } else {
             None
         };
+        // parse the optional where clause, bounding the wrapped type marked by an underscore
+        let where_clause = if input.peek(Token![where]) {
+            Some(input.parse::<WhereClause>()?)
+        } else {
+            None
+        };
+        let bounds = where_clause.as_ref().map(|clause| ParamBounds {
+            param: wrapped_param(generics.as_ref())
+                .unwrap_or_else(|| Ident::new("_", clause.where_token.span)),
+            predicates: clause.predicates.iter().cloned().collect(),
+        });
         // parse the operations block
         let content;
         let _ = braced! { content in input };
         let mut ops = Punctuated::new();
         while !content.is_empty() {
-            ops.push(content.parse::<MethodCallAst>()?);
+            ops.push(content.parse::<Op>()?);
             if content.peek(Token![,]) {
                 content.parse::<Token![,]>()?;
             }
@@ -89,9 +347,137 @@ impl Parse for WrapperImpls {
             impl_token,
             generics,
             target,
+            args,
             field,
+            ctor,
             where_clause,
             ops,
+            bounds,
         })
     }
 }
+
+impl<Op> WrapperImpls<Op> {
+    /// returns the additional type parameters of the target, e.g. `U` for `Quantity<_, U>`
+    pub fn params(&self) -> Vec<&GenericArgument> {
+        self.args
+            .iter()
+            .flat_map(|args| args.args.iter())
+            .filter(|arg| !is_infer(arg))
+            .collect()
+    }
+    /// returns the type of the target wrapping the given type, e.g. `Quantity<_A, U>`
+    pub fn target_ty(&self, inner: &TokenStream) -> TokenStream {
+        let target = &self.target;
+        match &self.args {
+            None => quote!(#target<#inner>),
+            Some(args) => {
+                let args = args.args.iter().map(|arg| match is_infer(arg) {
+                    true => inner.clone(),
+                    false => arg.to_token_stream(),
+                });
+                quote!(#target<#(#args),*>)
+            }
+        }
+    }
+    /// returns an expression constructing the target from the given value using its
+    /// constructor, if any; otherwise, targets declaring additional parameters are constructed
+    /// using their `new` function, since they may hold other fields, e.g. a `PhantomData` marker
+    pub fn construct(&self, field: &Member, value: TokenStream) -> TokenStream {
+        let target = &self.target;
+        match (&self.ctor, &self.args) {
+            (Some(ctor), _) => quote!(#ctor(#value)),
+            (None, None) => quote!(#target { #field: #value }),
+            (None, Some(_)) => quote!(#target::new(#value)),
+        }
+    }
+    /// returns the bounds of the type parameter of the target, substituting the given type for
+    /// the parameter, e.g. `_A: Copy` for `T: Copy`
+    pub fn bounds(&self, inner: &TokenStream) -> Vec<TokenStream> {
+        self.bounds
+            .iter()
+            .flat_map(|bounds| {
+                bounds.predicates.iter().map(|predicate| {
+                    replace_ident(predicate.to_token_stream(), &bounds.param, inner)
+                })
+            })
+            .collect()
+    }
+    /// returns an error if the target was given any additional parameters, a constructor or a
+    /// where clause
+    pub fn deny_params(&self) -> syn::Result<()> {
+        if let Some(args) = &self.args {
+            return Err(syn::Error::new_spanned(
+                args,
+                "the target does not accept any parameters",
+            ));
+        }
+        if let Some(ctor) = &self.ctor {
+            return Err(syn::Error::new_spanned(
+                ctor,
+                "the target does not accept a constructor",
+            ));
+        }
+        if let Some(clause) = &self.where_clause {
+            return Err(syn::Error::new_spanned(
+                clause,
+                "the target does not accept a where clause",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// replaces each occurrence of the identifier within the tokens by the given tokens
+fn replace_ident(tokens: TokenStream, ident: &Ident, with: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ref i) if i == ident => with.clone(),
+            TokenTree::Group(group) => {
+                let stream = replace_ident(group.stream(), ident, with);
+                let mut replaced = proc_macro2::Group::new(group.delimiter(), stream);
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into()
+            }
+            tree => tree.into(),
+        })
+        .collect()
+}
+
+/// returns the name given to the wrapped type by the generics of the input, e.g. `T` for
+/// `impl<T> Wrapper where T: Copy { ... }`
+fn wrapped_param(generics: Option<&AngleBracketedGenericArguments>) -> Option<Ident> {
+    match generics?.args.iter().collect::<Vec<_>>().as_slice() {
+        [GenericArgument::Type(Type::Path(ty))] if ty.qself.is_none() => {
+            ty.path.get_ident().cloned()
+        }
+        _ => None,
+    }
+}
+
+/// returns true if the argument is the `_` placeholder of the wrapped type
+fn is_infer(arg: &GenericArgument) -> bool {
+    matches!(arg, GenericArgument::Type(Type::Infer(_)))
+}
+
+/// ensures the parameters of the target contain a single placeholder for the wrapped type,
+/// with each of the others naming a type parameter
+fn validate_args(args: &AngleBracketedGenericArguments) -> syn::Result<()> {
+    if args.args.iter().filter(|arg| is_infer(arg)).count() != 1 {
+        return Err(syn::Error::new_spanned(
+            args,
+            "expected a single `_` marking the wrapped type, e.g. `Quantity<_, U>`",
+        ));
+    }
+    for arg in args.args.iter().filter(|arg| !is_infer(arg)) {
+        match arg {
+            GenericArgument::Type(Type::Path(path))
+                if path.qself.is_none() && path.path.get_ident().is_some() => {}
+            _ => {
+                return Err(syn::Error::new_spanned(arg, "expected a type parameter"));
+            }
+        }
+    }
+    Ok(())
+}
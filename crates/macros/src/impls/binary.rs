@@ -2,246 +2,451 @@
     appellation: impl_binary <module>
     authors: @FL03
 */
-use crate::ast::{MethodCallAst, WrapperImpls};
+use crate::ast::{MethodCallAst, OperandAst, WrapperImpls};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::Ident;
-
-/// Procedural macro entry point
-pub fn impl_wrapper_binary_ops(input: WrapperImpls) -> TokenStream {
-    let base = impl_core_binary_ops(&input);
-    let assign = impl_assign_ops(&input);
+use syn::{Ident, Member, Type};
+
+/// the ways in which an operand may be passed to an operation
+#[derive(Clone, Copy)]
+enum Ref {
+    Owned,
+    Shared,
+    Mut,
+}
 
-    quote! {
-        #(#base)*
+/// the combinations of the operands, `(self, rhs)`, implemented for each operation
+const REFS: [(Ref, Ref); 7] = [
+    (Ref::Owned, Ref::Owned),
+    (Ref::Owned, Ref::Shared),
+    (Ref::Shared, Ref::Shared),
+    (Ref::Shared, Ref::Owned),
+    (Ref::Owned, Ref::Mut),
+    (Ref::Mut, Ref::Mut),
+    (Ref::Mut, Ref::Owned),
+];
+
+/// a wrapper type participating in an operation along with the field holding its value
+struct Operand<'a> {
+    ty: TokenStream,
+    field: &'a Member,
+    /// the input of the macro whenever the operand is the target itself
+    target: Option<&'a WrapperImpls>,
+}
 
-        #(#assign)*
+impl Operand<'_> {
+    /// returns the type of the operand wrapping the given type
+    fn of(&self, inner: TokenStream) -> TokenStream {
+        match self.target {
+            Some(input) => input.target_ty(&inner),
+            None => {
+                let ty = &self.ty;
+                quote!(#ty<#inner>)
+            }
+        }
+    }
+    /// returns the bounds of the operand upon the given type, if it is the target
+    fn bounds(&self, inner: TokenStream) -> Vec<TokenStream> {
+        self.target
+            .map(|input| input.bounds(&inner))
+            .unwrap_or_default()
+    }
+    /// returns an expression constructing the operand from the given value
+    fn build(&self, value: TokenStream) -> TokenStream {
+        match self.target {
+            Some(input) => input.construct(self.field, value),
+            None => {
+                let (ty, field) = (&self.ty, self.field);
+                quote!(#ty { #field: #value })
+            }
+        }
     }
 }
 
-fn impl_core_binary_ops(
-    WrapperImpls {
-        target, field, ops, ..
-    }: &WrapperImpls,
-) -> Vec<TokenStream> {
-    let mut impls = Vec::new();
-    for MethodCallAst { name: op, call, .. } in ops {
-        let _impl = if let Some(f) = field {
-            impl_named(op, target, call, f)
-        } else {
-            impl_unnamed(op, target, call)
-        };
-        impls.push(_impl);
+impl Ref {
+    /// returns the type of the operand, given the type of the wrapper
+    fn wrap(self, ty: TokenStream) -> TokenStream {
+        match self {
+            Self::Owned => ty,
+            Self::Shared => quote!(&'a #ty),
+            Self::Mut => quote!(&'a mut #ty),
+        }
+    }
+    /// returns an expression accessing the field of the operand
+    fn access(self, ident: TokenStream, field: &Member) -> TokenStream {
+        match self {
+            Self::Owned => quote!(#ident.#field),
+            Self::Shared => quote!(&#ident.#field),
+            Self::Mut => quote!(&mut #ident.#field),
+        }
     }
-    impls
 }
 
-fn impl_assign_ops(options: &WrapperImpls) -> Vec<TokenStream> {
-    let WrapperImpls {
-        target, field, ops, ..
-    } = options;
+/// Procedural macro entry point
+pub fn impl_wrapper_binary_ops(input: WrapperImpls) -> TokenStream {
+    impl_binary_ops(&input, true)
+}
 
+/// implements the binary operations of the input, along with their assign counterparts if
+/// `assign` is true
+pub fn impl_binary_ops(input: &WrapperImpls, assign: bool) -> TokenStream {
+    let WrapperImpls { field, ops, .. } = input;
+    let field = match field {
+        Some(f) => Member::Named(f.clone()),
+        None => Member::from(0),
+    };
     let mut impls = Vec::new();
-    for MethodCallAst { name: op, call, .. } in ops {
-        let op_assign = format_ident!("{}Assign", op);
-        let call_assign = format_ident!("{}_assign", call);
+    for op in ops {
+        match impl_binary_op(op, input, &field, assign) {
+            Ok(_impl) => impls.push(_impl),
+            Err(err) => return err.to_compile_error(),
+        }
+    }
+    let iter = impl_iter_folds(input, &field);
 
-        let _impl = if let Some(f) = field {
-            quote! {
-                impl<_A, _B> ::core::ops::#op_assign<#target<_B>> for #target<_A>
-                where
-                    _A: ::core::ops::#op_assign<_B>,
-                {
-                    fn #call_assign(&mut self, rhs: #target<_B>) {
-                        ::core::ops::#op_assign::#call_assign(&mut self.#f, rhs.#f)
-                    }
+    quote! {
+        #(#impls)*
+
+        #(#iter)*
+    }
+}
+
+/// implements a single operation for each combination of operands, along with its assign
+/// counterpart whenever the output is the target itself
+fn impl_binary_op(
+    op: &MethodCallAst,
+    input: &WrapperImpls,
+    field: &Member,
+    assign: bool,
+) -> syn::Result<TokenStream> {
+    if op.scalar.is_some() {
+        return impl_scalar_op(op, input, field, assign);
+    }
+    let lhs = target_operand(input, field);
+    // resolve the right-hand side of the operation, defaulting to the target
+    let rhs = match &op.args {
+        None => target_operand(input, field),
+        Some(args) if args.len() == 1 => operand(&args[0], field)?,
+        Some(args) => {
+            return Err(syn::Error::new_spanned(
+                args,
+                "expected a single right-hand wrapper type",
+            ));
+        }
+    };
+    // resolve the output of the operation, defaulting to the target
+    let out = match &op.output {
+        None => target_operand(input, field),
+        Some(output) => operand(&output.operand, field)?,
+    };
+    let trait_path = op.trait_path();
+    let call = &op.call;
+    let params = input.params();
+    let (lhs_bounds, rhs_bounds, out_bounds) = (
+        lhs.bounds(quote!(_A)),
+        rhs.bounds(quote!(_B)),
+        out.bounds(quote!(_C)),
+    );
+    let base = REFS.iter().map(|&(l, r)| {
+        let generics = match (l, r) {
+            (Ref::Owned, Ref::Owned) => quote!(<_A, _B, _C #(, #params)*>),
+            _ => quote!(<'a, _A, _B, _C #(, #params)*>),
+        };
+        let self_ty = l.wrap(lhs.of(quote!(_A)));
+        let rhs_wrapper = r.wrap(rhs.of(quote!(_B)));
+        let (lhs_inner, rhs_inner) = (l.wrap(quote!(_A)), r.wrap(quote!(_B)));
+        let (lhs_value, rhs_value) = (
+            l.access(quote!(self), lhs.field),
+            r.access(quote!(rhs), rhs.field),
+        );
+        let out_ty = out.of(quote!(_C));
+        let value = out.build(quote! {
+            <#lhs_inner as #trait_path<#rhs_inner>>::#call(#lhs_value, #rhs_value)
+        });
+        quote! {
+            impl #generics #trait_path<#rhs_wrapper> for #self_ty
+            where
+                #lhs_inner: #trait_path<#rhs_inner, Output = _C>,
+                #(#lhs_bounds,)*
+                #(#rhs_bounds,)*
+                #(#out_bounds,)*
+            {
+                type Output = #out_ty;
+
+                fn #call(self, rhs: #rhs_wrapper) -> Self::Output {
+                    #value
                 }
             }
-        } else {
+        }
+    });
+    // the assign operation is only available when the output is the target itself
+    let is_target = out.ty.to_string() == lhs.ty.to_string() && out.field == lhs.field;
+    let assign = op
+        .assign_op()
+        .filter(|_| assign && is_target)
+        .map(|(op_assign, call_assign)| {
+            let (self_ty, rhs_ty) = (lhs.of(quote!(_A)), rhs.of(quote!(_B)));
+            let rhs_field = rhs.field;
+            let (bound, body) = assign_body(
+                input,
+                field,
+                &trait_path,
+                call,
+                (&op_assign, &call_assign),
+                quote!(_B),
+                quote!(rhs.#rhs_field),
+            );
             quote! {
-                impl<_A, _B> ::core::ops::#op_assign<#target<_B>> for #target<_A>
+                impl<_A, _B #(, #params)*> #op_assign<#rhs_ty> for #self_ty
                 where
-                    _A: ::core::ops::#op_assign<_B>,
+                    _A: #bound,
+                    #(#lhs_bounds,)*
+                    #(#rhs_bounds,)*
                 {
-                    fn #call_assign(&mut self, rhs: #target<_B>) {
-                        ::core::ops::#op_assign::#call_assign(&mut self.0, rhs.0)
+                    fn #call_assign(&mut self, rhs: #rhs_ty) {
+                        #body
                     }
                 }
             }
-        };
-        // register the implementation
-        impls.push(_impl);
-    }
-    impls
-}
-
-fn impl_unnamed(op: &Ident, target: &Ident, call: &Ident) -> TokenStream {
-    quote! {
-        impl<_A, _B, _C> ::core::ops::#op<#target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(self.0, rhs.0))
-            }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a #target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<&'a _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(self.0, &rhs.0))
-            }
-        }
+        });
+    Ok(quote! {
+        #(#base)*
 
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a #target<_B>> for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<&'a _B, Output = _C>,
-        {
-            type Output = #target<_C>;
+        #assign
+    })
+}
 
-            fn #call(self, rhs: &'a #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(&self.0, &rhs.0))
-            }
+/// implements a single operation between the target and each of the scalar types, in either
+/// order, along with its assign counterpart whenever the output is the target itself
+fn impl_scalar_op(
+    op: &MethodCallAst,
+    input: &WrapperImpls,
+    field: &Member,
+    assign: bool,
+) -> syn::Result<TokenStream> {
+    let lhs = target_operand(input, field);
+    // resolve the output of the operation, defaulting to the target
+    let out = match &op.output {
+        None => target_operand(input, field),
+        Some(output) => operand(&output.operand, field)?,
+    };
+    let is_target = out.ty.to_string() == lhs.ty.to_string() && out.field == field;
+    let assign_op = op.assign_op().filter(|_| assign && is_target);
+    let trait_path = op.trait_path();
+    let call = &op.call;
+    let params = input.params();
+    let out_ty = out.of(quote!(_C));
+    let out_bounds = out.bounds(quote!(_C));
+    let mut impls = Vec::new();
+    for OperandAst { ty, field: member } in op.args.iter().flatten() {
+        if let Some((dot, member)) = member {
+            return Err(syn::Error::new_spanned(
+                quote!(#dot #member),
+                "scalar operands do not accept a field",
+            ));
         }
+        // the scalar is the right-hand side of the operation
+        let forward = [Ref::Owned, Ref::Shared].map(|l| {
+            let generics = match l {
+                Ref::Owned => quote!(<_A, _C #(, #params)*>),
+                _ => quote!(<'a, _A, _C #(, #params)*>),
+            };
+            let self_ty = l.wrap(lhs.of(quote!(_A)));
+            let lhs_inner = l.wrap(quote!(_A));
+            let lhs_bounds = lhs.bounds(quote!(_A));
+            let lhs_value = l.access(quote!(self), field);
+            let value = out.build(quote! {
+                <#lhs_inner as #trait_path<#ty>>::#call(#lhs_value, rhs)
+            });
+            quote! {
+                impl #generics #trait_path<#ty> for #self_ty
+                where
+                    #lhs_inner: #trait_path<#ty, Output = _C>,
+                    #(#lhs_bounds,)*
+                    #(#out_bounds,)*
+                {
+                    type Output = #out_ty;
 
-        impl<'a, _A, _B, _C> ::core::ops::#op<#target<_B>> for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(&self.0, rhs.0))
+                    fn #call(self, rhs: #ty) -> Self::Output {
+                        #value
+                    }
+                }
             }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a mut #target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<&'a mut _B, Output = _C>,
-        {
-            type Output = #target<_C>;
+        });
+        // the scalar is the left-hand side of the operation, unless it is restricted to the
+        // right-hand side; borrowed wrappers are omitted since their candidates overflow the
+        // trait solver whenever the scalar is inferred
+        let reverse = op.rhs.is_none().then(|| {
+            let rhs_ty = lhs.of(quote!(_B));
+            let rhs_bounds = lhs.bounds(quote!(_B));
+            let value = out.build(quote! {
+                <#ty as #trait_path<_B>>::#call(self, rhs.#field)
+            });
+            quote! {
+                impl<_B, _C #(, #params)*> #trait_path<#rhs_ty> for #ty
+                where
+                    #ty: #trait_path<_B, Output = _C>,
+                    #(#rhs_bounds,)*
+                    #(#out_bounds,)*
+                {
+                    type Output = #out_ty;
 
-            fn #call(self, rhs: &'a mut #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(self.0, &mut rhs.0))
+                    fn #call(self, rhs: #rhs_ty) -> Self::Output {
+                        #value
+                    }
+                }
             }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a mut #target<_B>> for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<&'a mut _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a mut #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(&mut self.0, &mut rhs.0))
+        });
+        let assign = assign_op.as_ref().map(|(op_assign, call_assign)| {
+            let self_ty = lhs.of(quote!(_A));
+            let lhs_bounds = lhs.bounds(quote!(_A));
+            let (bound, body) = assign_body(
+                input,
+                field,
+                &trait_path,
+                call,
+                (op_assign, call_assign),
+                quote!(#ty),
+                quote!(rhs),
+            );
+            quote! {
+                impl<_A #(, #params)*> #op_assign<#ty> for #self_ty
+                where
+                    _A: #bound,
+                    #(#lhs_bounds,)*
+                {
+                    fn #call_assign(&mut self, rhs: #ty) {
+                        #body
+                    }
+                }
             }
-        }
+        });
+        impls.push(quote! {
+            #(#forward)*
 
-        impl<'a, _A, _B, _C> ::core::ops::#op<#target<_B>> for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
+            #reverse
 
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                #target(::core::ops::#op::#call(&mut self.0, rhs.0))
-            }
-        }
+            #assign
+        });
     }
+    Ok(quote! {
+        #(#impls)*
+    })
 }
 
-fn impl_named(op: &Ident, target: &Ident, call: &Ident, field: &Ident) -> TokenStream {
-    quote! {
-        impl<_A, _B, _C> ::core::ops::#op<#target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(self.#field, rhs.#field);
-                #target { #field }
-            }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a #target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<&'a _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(self.#field, &rhs.#field);
-                #target { #field }
-            }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a #target<_B>> for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<&'a _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&self.#field, &rhs.#field);
-                #target { #field }
-            }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<#target<_B>> for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&self.#field, rhs.#field);
-                #target { #field }
-            }
+/// returns the bound placed upon the wrapped type, `_A`, along with the body of an assign
+/// operation; targets given a constructor are rebuilt from the result of the operation,
+/// ensuring it is checked, while the others assign their field in place
+fn assign_body(
+    input: &WrapperImpls,
+    field: &Member,
+    trait_path: &TokenStream,
+    call: &Ident,
+    (op_assign, call_assign): (&TokenStream, &Ident),
+    rhs_ty: TokenStream,
+    rhs: TokenStream,
+) -> (TokenStream, TokenStream) {
+    match &input.ctor {
+        None => (
+            quote!(#op_assign<#rhs_ty>),
+            quote!(#op_assign::#call_assign(&mut self.#field, #rhs)),
+        ),
+        Some(_) => {
+            // the field is replaced by its default while the result is being checked
+            let value = input.construct(field, quote!(#trait_path::#call(lhs, #rhs)));
+            (
+                quote!(#trait_path<#rhs_ty, Output = _A> + ::core::default::Default),
+                quote! {
+                    let lhs = ::core::mem::take(&mut self.#field);
+                    *self = #value;
+                },
+            )
         }
+    }
+}
 
+/// returns the target of the macro input as an operand
+fn target_operand<'a>(input: &'a WrapperImpls, field: &'a Member) -> Operand<'a> {
+    let target = &input.target;
+    Operand {
+        ty: quote!(#target),
+        field,
+        target: Some(input),
+    }
+}
 
+/// resolves an operand of the macro input, defaulting its field to that of the target
+fn operand<'a>(
+    OperandAst { ty, field }: &'a OperandAst,
+    default: &'a Member,
+) -> syn::Result<Operand<'a>> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => Ok(Operand {
+            ty: quote!(#path),
+            field: field.as_ref().map_or(default, |(_, member)| member),
+            target: None,
+        }),
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "expected the path of a wrapper type",
+        )),
+    }
+}
 
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a mut #target<_B>> for #target<_A>
-        where
-            _A: ::core::ops::#op<&'a mut _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a mut #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(self.#field, &mut rhs.#field);
-                #target { #field }
-            }
+/// implements the [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product) traits
+/// whenever the `Add` and `Mul` operations are requested, respectively
+fn impl_iter_folds(input: &WrapperImpls, field: &Member) -> Vec<TokenStream> {
+    let params = input.params();
+    let target = input.target_ty(&quote!(_A));
+    let bounds = input.bounds(&quote!(_A));
+    let mut impls = Vec::new();
+    for op in &input.ops {
+        // only operations between wrappers of the same type may be folded
+        if op.args.is_some() || op.output.is_some() {
+            continue;
         }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<&'a mut #target<_B>> for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<&'a mut _B, Output = _C>,
-        {
-            type Output = #target<_C>;
-
-            fn #call(self, rhs: &'a mut #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&mut self.#field, &mut rhs.#field);
-                #target { #field }
+        let (fold, call) = if op.is_core_op("Add") {
+            (format_ident!("Sum"), format_ident!("sum"))
+        } else if op.is_core_op("Mul") {
+            (format_ident!("Product"), format_ident!("product"))
+        } else {
+            continue;
+        };
+        let owned = input.construct(
+            field,
+            quote!(::core::iter::#fold::#call(iter.map(|item| item.#field))),
+        );
+        let borrowed = input.construct(
+            field,
+            quote!(::core::iter::#fold::#call(iter.map(|item| &item.#field))),
+        );
+        impls.push(quote! {
+            impl<_A #(, #params)*> ::core::iter::#fold for #target
+            where
+                _A: ::core::iter::#fold,
+                #(#bounds,)*
+            {
+                fn #call<_I>(iter: _I) -> Self
+                where
+                    _I: Iterator<Item = Self>,
+                {
+                    #owned
+                }
             }
-        }
-
-        impl<'a, _A, _B, _C> ::core::ops::#op<#target<_B>> for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<_B, Output = _C>,
-        {
-            type Output = #target<_C>;
 
-            fn #call(self, rhs: #target<_B>) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&mut self.#field, rhs.#field);
-                #target { #field }
+            impl<'a, _A #(, #params)*> ::core::iter::#fold<&'a #target> for #target
+            where
+                _A: ::core::iter::#fold<&'a _A>,
+                #(#bounds,)*
+            {
+                fn #call<_I>(iter: _I) -> Self
+                where
+                    _I: Iterator<Item = &'a #target>,
+                {
+                    #borrowed
+                }
             }
-        }
+        });
     }
+    impls
 }
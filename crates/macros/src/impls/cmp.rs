@@ -0,0 +1,124 @@
+/*
+    appellation: impl_cmp <module>
+    authors: @FL03
+*/
+use crate::ast::{MethodCallAst, WrapperImpls};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Type};
+
+/// Procedural macro entry point
+pub fn impl_wrapper_cmp_ops(input: WrapperImpls) -> TokenStream {
+    // the target is assumed to wrap its single type parameter
+    if let Err(err) = input.deny_params() {
+        return err.to_compile_error();
+    }
+    let WrapperImpls {
+        target, field, ops, ..
+    } = &input;
+    // resolve the accessor for the wrapped value
+    let access = match field {
+        Some(f) => quote!(#f),
+        None => quote!(0),
+    };
+    let mut impls = Vec::new();
+    for op in ops {
+        match impl_cmp_op(op, target, &access) {
+            Ok(_impl) => impls.push(_impl),
+            Err(err) => return err.to_compile_error(),
+        }
+    }
+    quote! {
+        #(#impls)*
+    }
+}
+
+fn impl_cmp_op(
+    op: &MethodCallAst,
+    target: &Ident,
+    field: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let MethodCallAst {
+        name, call, args, ..
+    } = op;
+    op.deny_scalar()?;
+    op.deny_output()?;
+    op.deny_assign()?;
+    let (expected, method, output) = if name.is_ident("PartialEq") {
+        ("eq", quote!(eq), quote!(bool))
+    } else if name.is_ident("PartialOrd") {
+        (
+            "partial_cmp",
+            quote!(partial_cmp),
+            quote!(Option<::core::cmp::Ordering>),
+        )
+    } else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "expected one of `PartialEq` or `PartialOrd`",
+        ));
+    };
+    if call != expected {
+        return Err(syn::Error::new_spanned(
+            call,
+            format!("expected `{}.{expected}`", quote!(#name)),
+        ));
+    }
+    // split the arguments into the cross-wrapper marker and the concrete types
+    let mut types = Vec::new();
+    for operand in args.iter().flatten() {
+        if let Some((dot, member)) = &operand.field {
+            return Err(syn::Error::new_spanned(
+                quote!(#dot #member),
+                "the operands of a comparison do not accept a field",
+            ));
+        }
+        types.push(&operand.ty);
+    }
+    let cross = types.iter().any(|ty| is_self(ty));
+    let concrete = types.into_iter().filter(|ty| !is_self(ty));
+    // the cross-wrapper and inner comparisons overlap, so only one of them is generated
+    let base = if cross {
+        quote! {
+            impl<_A, _B> ::core::cmp::#name<#target<_B>> for #target<_A>
+            where
+                _A: ::core::cmp::#name<_B>,
+            {
+                fn #method(&self, other: &#target<_B>) -> #output {
+                    ::core::cmp::#name::#method(&self.#field, &other.#field)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl<_A> ::core::cmp::#name<_A> for #target<_A>
+            where
+                _A: ::core::cmp::#name,
+            {
+                fn #method(&self, other: &_A) -> #output {
+                    ::core::cmp::#name::#method(&self.#field, other)
+                }
+            }
+        }
+    };
+    // implement the reverse comparisons for each of the concrete types
+    let reverse = concrete.map(|ty| {
+        quote! {
+            impl ::core::cmp::#name<#target<#ty>> for #ty {
+                fn #method(&self, other: &#target<#ty>) -> #output {
+                    ::core::cmp::#name::#method(self, &other.#field)
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        #base
+
+        #(#reverse)*
+    })
+}
+
+/// returns true if the type is the `Self` keyword
+fn is_self(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self"))
+}
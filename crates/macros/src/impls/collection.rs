@@ -0,0 +1,212 @@
+/*
+    appellation: impl_collection <module>
+    authors: @FL03
+*/
+use crate::ast::{CollectionGroup, CollectionSet, WrapperImpls};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Procedural macro entry point
+pub fn impl_wrapper_collection(input: WrapperImpls<CollectionGroup>) -> TokenStream {
+    // the target is assumed to wrap its single type parameter
+    if let Err(err) = input.deny_params() {
+        return err.to_compile_error();
+    }
+    let WrapperImpls {
+        target, field, ops, ..
+    } = &input;
+    // resolve the accessor for the wrapped value
+    let access = match field {
+        Some(f) => quote!(#f),
+        None => quote!(0),
+    };
+    let set = ops.iter().copied().collect::<CollectionSet>();
+    // both groups define the `len` and `is_empty` methods
+    if set.len && set.text {
+        return syn::Error::new_spanned(
+            target,
+            "the `text` group cannot be combined with `len` or `all`",
+        )
+        .to_compile_error();
+    }
+    let iter = set.iter.then(|| impl_iter(target, &access));
+    let len = set.len.then(|| impl_len(target, &access));
+    let text = set.text.then(|| impl_text(target, &access));
+    let collect = set.collect.then(|| impl_collect(target, &access));
+    let index = set.index.then(|| impl_index(target, &access));
+    quote! {
+        #iter
+
+        #len
+
+        #text
+
+        #collect
+
+        #index
+    }
+}
+
+/// implements `IntoIterator` for owned, borrowed and mutably borrowed wrappers, along with the
+/// `iter` and `iter_mut` methods
+fn impl_iter(target: &Ident, field: &TokenStream) -> TokenStream {
+    quote! {
+        impl<_A> ::core::iter::IntoIterator for #target<_A>
+        where
+            _A: ::core::iter::IntoIterator,
+        {
+            type Item = <_A as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <_A as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                <_A as ::core::iter::IntoIterator>::into_iter(self.#field)
+            }
+        }
+
+        impl<'a, _A> ::core::iter::IntoIterator for &'a #target<_A>
+        where
+            &'a _A: ::core::iter::IntoIterator,
+        {
+            type Item = <&'a _A as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'a _A as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                <&'a _A as ::core::iter::IntoIterator>::into_iter(&self.#field)
+            }
+        }
+
+        impl<'a, _A> ::core::iter::IntoIterator for &'a mut #target<_A>
+        where
+            &'a mut _A: ::core::iter::IntoIterator,
+        {
+            type Item = <&'a mut _A as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <&'a mut _A as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                <&'a mut _A as ::core::iter::IntoIterator>::into_iter(&mut self.#field)
+            }
+        }
+
+        impl<_A> #target<_A> {
+            /// returns an iterator over references to the items of the inner collection
+            pub fn iter<'a>(&'a self) -> <&'a _A as ::core::iter::IntoIterator>::IntoIter
+            where
+                &'a _A: ::core::iter::IntoIterator,
+            {
+                <&'a _A as ::core::iter::IntoIterator>::into_iter(&self.#field)
+            }
+            /// returns an iterator over mutable references to the items of the inner collection
+            pub fn iter_mut<'a>(&'a mut self) -> <&'a mut _A as ::core::iter::IntoIterator>::IntoIter
+            where
+                &'a mut _A: ::core::iter::IntoIterator,
+            {
+                <&'a mut _A as ::core::iter::IntoIterator>::into_iter(&mut self.#field)
+            }
+        }
+    }
+}
+
+/// implements the `len` and `is_empty` methods for collections whose borrowed iterator knows
+/// its exact length
+fn impl_len(target: &Ident, field: &TokenStream) -> TokenStream {
+    quote! {
+        impl<_A> #target<_A>
+        where
+            for<'a> &'a _A: ::core::iter::IntoIterator<
+                IntoIter: ::core::iter::ExactSizeIterator
+            >,
+        {
+            /// returns the number of items within the inner collection
+            pub fn len(&self) -> usize {
+                ::core::iter::ExactSizeIterator::len(
+                    &::core::iter::IntoIterator::into_iter(&self.#field),
+                )
+            }
+            /// returns true if the inner collection contains no items
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+        }
+    }
+}
+
+/// implements the `len`, `is_empty` and `chars` methods for wrappers over string slices, e.g.
+/// a `String`, whose characters are not iterated through `IntoIterator`
+fn impl_text(target: &Ident, field: &TokenStream) -> TokenStream {
+    quote! {
+        impl<_A> #target<_A>
+        where
+            _A: ::core::convert::AsRef<str>,
+        {
+            /// returns the length of the inner string, in bytes
+            pub fn len(&self) -> usize {
+                <_A as ::core::convert::AsRef<str>>::as_ref(&self.#field).len()
+            }
+            /// returns true if the inner string has a length of zero bytes
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+            /// returns an iterator over the characters of the inner string
+            pub fn chars(&self) -> ::core::str::Chars<'_> {
+                <_A as ::core::convert::AsRef<str>>::as_ref(&self.#field).chars()
+            }
+        }
+    }
+}
+
+/// implements `FromIterator` and `Extend` for any item accepted by the inner collection
+fn impl_collect(target: &Ident, field: &TokenStream) -> TokenStream {
+    quote! {
+        impl<_A, _T> ::core::iter::FromIterator<_T> for #target<_A>
+        where
+            _A: ::core::iter::FromIterator<_T>,
+        {
+            fn from_iter<_I>(iter: _I) -> Self
+            where
+                _I: ::core::iter::IntoIterator<Item = _T>,
+            {
+                Self {
+                    #field: <_A as ::core::iter::FromIterator<_T>>::from_iter(iter),
+                }
+            }
+        }
+
+        impl<_A, _T> ::core::iter::Extend<_T> for #target<_A>
+        where
+            _A: ::core::iter::Extend<_T>,
+        {
+            fn extend<_I>(&mut self, iter: _I)
+            where
+                _I: ::core::iter::IntoIterator<Item = _T>,
+            {
+                <_A as ::core::iter::Extend<_T>>::extend(&mut self.#field, iter)
+            }
+        }
+    }
+}
+
+/// implements `Index` and `IndexMut` for any index accepted by the inner collection
+fn impl_index(target: &Ident, field: &TokenStream) -> TokenStream {
+    quote! {
+        impl<_A, _I> ::core::ops::Index<_I> for #target<_A>
+        where
+            _A: ::core::ops::Index<_I>,
+        {
+            type Output = <_A as ::core::ops::Index<_I>>::Output;
+
+            fn index(&self, index: _I) -> &Self::Output {
+                <_A as ::core::ops::Index<_I>>::index(&self.#field, index)
+            }
+        }
+
+        impl<_A, _I> ::core::ops::IndexMut<_I> for #target<_A>
+        where
+            _A: ::core::ops::IndexMut<_I>,
+        {
+            fn index_mut(&mut self, index: _I) -> &mut Self::Output {
+                <_A as ::core::ops::IndexMut<_I>>::index_mut(&mut self.#field, index)
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+/*
+    appellation: impl_int <module>
+    authors: @FL03
+*/
+use crate::ast::WrapperImpls;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{GenericArgument, Ident, Type};
+
+/// the shape of the value returned by a method family
+enum Family {
+    /// `checked_*` methods returning an `Option`
+    Checked,
+    /// `overflowing_*` methods returning a tuple with an overflow flag
+    Overflowing,
+    /// `wrapping_*` and `saturating_*` methods, as well as `pow`, returning the value itself
+    Total,
+}
+
+/// the parameters accepted by a method
+enum Operand {
+    /// `(self, rhs: Self)`
+    Wrapper,
+    /// `(self, rhs: u32)`, used by the shift and power methods
+    Exponent,
+    /// `(self)`
+    Unary,
+}
+
+/// Procedural macro entry point
+pub fn impl_wrapper_int_ops(input: WrapperImpls<Ident>) -> TokenStream {
+    // the target is assumed to wrap its single type parameter
+    if let Err(err) = input.deny_params() {
+        return err.to_compile_error();
+    }
+    match _impl_wrapper_int_ops(&input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn _impl_wrapper_int_ops(
+    WrapperImpls {
+        impl_token,
+        generics,
+        target,
+        field,
+        ops,
+        ..
+    }: &WrapperImpls<Ident>,
+) -> syn::Result<TokenStream> {
+    // the integer types are provided as the generics of the implementation
+    let types = generics
+        .iter()
+        .flat_map(|generics| generics.args.iter())
+        .map(|arg| match arg {
+            GenericArgument::Type(ty) => Ok(ty),
+            other => Err(syn::Error::new_spanned(other, "expected an integer type")),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    if types.is_empty() {
+        return Err(syn::Error::new_spanned(
+            impl_token,
+            "expected the integer types to implement, e.g. `impl<u8, i32> Wrapper { ... }`",
+        ));
+    }
+    // resolve the accessor for the wrapped value
+    let access = match field {
+        Some(f) => quote!(#f),
+        None => quote!(0),
+    };
+    let mut impls = Vec::new();
+    for ty in types {
+        let methods = ops
+            .iter()
+            .map(|method| impl_method(method, target, &access, ty))
+            .collect::<syn::Result<Vec<_>>>()?;
+        impls.push(quote! {
+            impl #target<#ty> {
+                #(#methods)*
+            }
+        });
+    }
+    Ok(quote! {
+        #(#impls)*
+    })
+}
+
+fn impl_method(
+    method: &Ident,
+    target: &Ident,
+    field: &TokenStream,
+    ty: &Type,
+) -> syn::Result<TokenStream> {
+    let name = method.to_string();
+    let doc = format!(
+        "forwards to the [`{name}`]({}::{name}) method of the inner value",
+        quote!(#ty)
+    );
+    // the absolute difference of signed integers is an unsigned integer
+    if name == "abs_diff" {
+        let unsigned = unsigned_of(ty)
+            .ok_or_else(|| syn::Error::new_spanned(ty, "`abs_diff` expects a primitive integer"))?;
+        return Ok(quote! {
+            #[doc = #doc]
+            #[inline]
+            pub const fn #method(self, rhs: Self) -> #target<#unsigned> {
+                #target { #field: self.#field.#method(rhs.#field) }
+            }
+        });
+    }
+    // split the method into its family and operation
+    let (family, op) = if let Some(op) = name.strip_prefix("checked_") {
+        (Family::Checked, op)
+    } else if let Some(op) = name.strip_prefix("overflowing_") {
+        (Family::Overflowing, op)
+    } else if let Some(op) = name
+        .strip_prefix("wrapping_")
+        .or_else(|| name.strip_prefix("saturating_"))
+    {
+        (Family::Total, op)
+    } else {
+        (Family::Total, name.as_str())
+    };
+    let operand = match op {
+        "add" | "sub" | "mul" | "div" | "rem" | "div_euclid" | "rem_euclid" => Operand::Wrapper,
+        "pow" | "shl" | "shr" => Operand::Exponent,
+        "neg" | "abs" => Operand::Unary,
+        _ => return Err(syn::Error::new_spanned(method, UNSUPPORTED)),
+    };
+    // a bare operation is only valid for `pow`
+    if op == name && op != "pow" {
+        return Err(syn::Error::new_spanned(method, UNSUPPORTED));
+    }
+    let (params, call) = match operand {
+        Operand::Wrapper => (
+            quote!(self, rhs: Self),
+            quote!(self.#field.#method(rhs.#field)),
+        ),
+        Operand::Exponent => (quote!(self, rhs: u32), quote!(self.#field.#method(rhs))),
+        Operand::Unary => (quote!(self), quote!(self.#field.#method())),
+    };
+    let output = match family {
+        Family::Checked => quote! {
+            pub const fn #method(#params) -> Option<Self> {
+                match #call {
+                    Some(value) => Some(Self { #field: value }),
+                    None => None,
+                }
+            }
+        },
+        Family::Overflowing => quote! {
+            pub const fn #method(#params) -> (Self, bool) {
+                let (value, overflow) = #call;
+                (Self { #field: value }, overflow)
+            }
+        },
+        Family::Total => quote! {
+            pub const fn #method(#params) -> Self {
+                Self { #field: #call }
+            }
+        },
+    };
+    Ok(quote! {
+        #[doc = #doc]
+        #[inline]
+        #output
+    })
+}
+
+const UNSUPPORTED: &str = "expected `pow`, `abs_diff` or a `checked_`, `overflowing_`, `saturating_` or `wrapping_` method \
+    for one of `add`, `sub`, `mul`, `div`, `rem`, `div_euclid`, `rem_euclid`, `pow`, `shl`, `shr`, `neg` or `abs`";
+
+/// returns the unsigned counterpart of a primitive integer type
+fn unsigned_of(ty: &Type) -> Option<Ident> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let ident = path.path.get_ident()?.to_string();
+    let unsigned = match ident.as_str() {
+        "i8" | "u8" => "u8",
+        "i16" | "u16" => "u16",
+        "i32" | "u32" => "u32",
+        "i64" | "u64" => "u64",
+        "i128" | "u128" => "u128",
+        "isize" | "usize" => "usize",
+        _ => return None,
+    };
+    Some(format_ident!("{}", unsigned))
+}
@@ -0,0 +1,410 @@
+/*
+    appellation: impl_num <module>
+    authors: @FL03
+*/
+use crate::ast::WrapperImpls;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// the constructors of `Float` returning a new instance
+const FLOAT_CONST: &[&str] = &[
+    "nan",
+    "infinity",
+    "neg_infinity",
+    "neg_zero",
+    "min_value",
+    "min_positive_value",
+    "epsilon",
+    "max_value",
+];
+/// the methods of `Float` mapping the value onto a new instance
+const FLOAT_UNARY: &[&str] = &[
+    "floor",
+    "ceil",
+    "round",
+    "trunc",
+    "fract",
+    "abs",
+    "signum",
+    "recip",
+    "sqrt",
+    "exp",
+    "exp2",
+    "ln",
+    "log2",
+    "log10",
+    "to_degrees",
+    "to_radians",
+    "cbrt",
+    "sin",
+    "cos",
+    "tan",
+    "asin",
+    "acos",
+    "atan",
+    "exp_m1",
+    "ln_1p",
+    "sinh",
+    "cosh",
+    "tanh",
+    "asinh",
+    "acosh",
+    "atanh",
+];
+/// the methods of `Float` combining the value with another instance
+const FLOAT_BINARY: &[&str] = &[
+    "powf", "log", "max", "min", "abs_sub", "hypot", "atan2", "copysign",
+];
+/// the methods of `Float` classifying the value
+const FLOAT_PREDICATE: &[&str] = &[
+    "is_nan",
+    "is_infinite",
+    "is_finite",
+    "is_normal",
+    "is_subnormal",
+    "is_sign_positive",
+    "is_sign_negative",
+];
+/// the primitive types converted by `FromPrimitive` and `ToPrimitive`, each naming the
+/// `from_*` and `to_*` methods of the traits
+const PRIMITIVES: &[&str] = &["i64", "u64", "i128", "u128", "f32", "f64"];
+
+/// Procedural macro entry point
+pub fn impl_wrapper_num_ops(input: WrapperImpls<Ident>) -> TokenStream {
+    // the target is assumed to wrap its single type parameter
+    if let Err(err) = input.deny_params() {
+        return err.to_compile_error();
+    }
+    let WrapperImpls {
+        target, field, ops, ..
+    } = &input;
+    // resolve the accessor for the wrapped value
+    let access = match field {
+        Some(f) => quote!(#f),
+        None => quote!(0),
+    };
+    let mut impls = Vec::new();
+    for op in ops {
+        match impl_num_trait(op, target, &access) {
+            Ok(_impl) => impls.push(_impl),
+            Err(err) => return err.to_compile_error(),
+        }
+    }
+    quote! {
+        #(#impls)*
+    }
+}
+
+fn impl_num_trait(name: &Ident, target: &Ident, field: &TokenStream) -> syn::Result<TokenStream> {
+    let num = quote!(::contained::num_traits);
+    let output = match name.to_string().as_str() {
+        // ********* identities & bounds *********
+        "Zero" => quote! {
+            impl<_A> #num::Zero for #target<_A>
+            where
+                _A: #num::Zero,
+                Self: ::core::ops::Add<Output = Self>,
+            {
+                fn zero() -> Self {
+                    #target { #field: <_A as #num::Zero>::zero() }
+                }
+
+                fn is_zero(&self) -> bool {
+                    #num::Zero::is_zero(&self.#field)
+                }
+            }
+        },
+        "One" => quote! {
+            impl<_A> #num::One for #target<_A>
+            where
+                _A: #num::One,
+                Self: ::core::ops::Mul<Output = Self>,
+            {
+                fn one() -> Self {
+                    #target { #field: <_A as #num::One>::one() }
+                }
+            }
+        },
+        "Bounded" => quote! {
+            impl<_A> #num::Bounded for #target<_A>
+            where
+                _A: #num::Bounded,
+            {
+                fn min_value() -> Self {
+                    #target { #field: <_A as #num::Bounded>::min_value() }
+                }
+
+                fn max_value() -> Self {
+                    #target { #field: <_A as #num::Bounded>::max_value() }
+                }
+            }
+        },
+        // ********* numeric classes *********
+        "Num" => quote! {
+            impl<_A> #num::Num for #target<_A>
+            where
+                _A: #num::Num,
+                Self: PartialEq + #num::Zero + #num::One + #num::NumOps,
+            {
+                type FromStrRadixErr = <_A as #num::Num>::FromStrRadixErr;
+
+                fn from_str_radix(src: &str, radix: u32) -> ::core::result::Result<Self, Self::FromStrRadixErr> {
+                    <_A as #num::Num>::from_str_radix(src, radix).map(|value| #target { #field: value })
+                }
+            }
+        },
+        "Signed" => quote! {
+            impl<_A> #num::Signed for #target<_A>
+            where
+                _A: #num::Signed,
+                Self: #num::Num + ::core::ops::Neg<Output = Self>,
+            {
+                fn abs(&self) -> Self {
+                    #target { #field: #num::Signed::abs(&self.#field) }
+                }
+
+                fn abs_sub(&self, other: &Self) -> Self {
+                    #target { #field: #num::Signed::abs_sub(&self.#field, &other.#field) }
+                }
+
+                fn signum(&self) -> Self {
+                    #target { #field: #num::Signed::signum(&self.#field) }
+                }
+
+                fn is_positive(&self) -> bool {
+                    #num::Signed::is_positive(&self.#field)
+                }
+
+                fn is_negative(&self) -> bool {
+                    #num::Signed::is_negative(&self.#field)
+                }
+            }
+        },
+        "Unsigned" => quote! {
+            impl<_A> #num::Unsigned for #target<_A>
+            where
+                _A: #num::Unsigned,
+                Self: #num::Num,
+            {
+            }
+        },
+        "Float" => impl_float(target, field),
+        // ********* conversions *********
+        "FromPrimitive" => {
+            let methods = PRIMITIVES.iter().map(|prim| {
+                let method = format_ident!("from_{prim}");
+                let prim = format_ident!("{prim}");
+                quote! {
+                    fn #method(n: #prim) -> ::core::option::Option<Self> {
+                        <_A as #num::FromPrimitive>::#method(n).map(|value| #target { #field: value })
+                    }
+                }
+            });
+            quote! {
+                impl<_A> #num::FromPrimitive for #target<_A>
+                where
+                    _A: #num::FromPrimitive,
+                {
+                    #(#methods)*
+                }
+            }
+        }
+        "ToPrimitive" => {
+            let methods = PRIMITIVES.iter().map(|prim| {
+                let method = format_ident!("to_{prim}");
+                let prim = format_ident!("{prim}");
+                quote! {
+                    fn #method(&self) -> ::core::option::Option<#prim> {
+                        #num::ToPrimitive::#method(&self.#field)
+                    }
+                }
+            });
+            quote! {
+                impl<_A> #num::ToPrimitive for #target<_A>
+                where
+                    _A: #num::ToPrimitive,
+                {
+                    #(#methods)*
+                }
+            }
+        }
+        "NumCast" => quote! {
+            impl<_A> #num::NumCast for #target<_A>
+            where
+                _A: #num::NumCast,
+                Self: #num::ToPrimitive,
+            {
+                fn from<_N: #num::ToPrimitive>(n: _N) -> ::core::option::Option<Self> {
+                    <_A as #num::NumCast>::from(n).map(|value| #target { #field: value })
+                }
+            }
+        },
+        "Pow" => quote! {
+            impl<_A, _R> #num::Pow<_R> for #target<_A>
+            where
+                _A: #num::Pow<_R>,
+            {
+                type Output = #target<<_A as #num::Pow<_R>>::Output>;
+
+                fn pow(self, rhs: _R) -> Self::Output {
+                    #target { #field: #num::Pow::pow(self.#field, rhs) }
+                }
+            }
+        },
+        // ********* checked, saturating & wrapping operations *********
+        "CheckedAdd" | "CheckedSub" | "CheckedMul" | "CheckedDiv" | "CheckedRem" => {
+            let (method, op) = split_family(name, "Checked");
+            quote! {
+                impl<_A> #num::#name for #target<_A>
+                where
+                    _A: #num::#name,
+                    Self: ::core::ops::#op<Output = Self>,
+                {
+                    fn #method(&self, v: &Self) -> ::core::option::Option<Self> {
+                        #num::#name::#method(&self.#field, &v.#field).map(|value| #target { #field: value })
+                    }
+                }
+            }
+        }
+        "SaturatingAdd" | "SaturatingSub" | "SaturatingMul" | "WrappingAdd" | "WrappingSub"
+        | "WrappingMul" => {
+            let family = if name.to_string().starts_with("Saturating") {
+                "Saturating"
+            } else {
+                "Wrapping"
+            };
+            let (method, op) = split_family(name, family);
+            quote! {
+                impl<_A> #num::#name for #target<_A>
+                where
+                    _A: #num::#name,
+                    Self: ::core::ops::#op<Output = Self>,
+                {
+                    fn #method(&self, v: &Self) -> Self {
+                        #target { #field: #num::#name::#method(&self.#field, &v.#field) }
+                    }
+                }
+            }
+        }
+        "CheckedNeg" => quote! {
+            impl<_A> #num::CheckedNeg for #target<_A>
+            where
+                _A: #num::CheckedNeg,
+            {
+                fn checked_neg(&self) -> ::core::option::Option<Self> {
+                    #num::CheckedNeg::checked_neg(&self.#field).map(|value| #target { #field: value })
+                }
+            }
+        },
+        "WrappingNeg" => quote! {
+            impl<_A> #num::WrappingNeg for #target<_A>
+            where
+                _A: #num::WrappingNeg,
+            {
+                fn wrapping_neg(&self) -> Self {
+                    #target { #field: #num::WrappingNeg::wrapping_neg(&self.#field) }
+                }
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(name, UNSUPPORTED)),
+    };
+    Ok(output)
+}
+
+/// implements `Float` by forwarding each of its methods to the wrapped value
+fn impl_float(target: &Ident, field: &TokenStream) -> TokenStream {
+    let num = quote!(::contained::num_traits);
+    let consts = FLOAT_CONST.iter().map(|method| {
+        let method = format_ident!("{method}");
+        quote! {
+            fn #method() -> Self {
+                #target { #field: <_A as #num::Float>::#method() }
+            }
+        }
+    });
+    let unary = FLOAT_UNARY.iter().map(|method| {
+        let method = format_ident!("{method}");
+        quote! {
+            fn #method(self) -> Self {
+                #target { #field: #num::Float::#method(self.#field) }
+            }
+        }
+    });
+    let binary = FLOAT_BINARY.iter().map(|method| {
+        let method = format_ident!("{method}");
+        quote! {
+            fn #method(self, other: Self) -> Self {
+                #target { #field: #num::Float::#method(self.#field, other.#field) }
+            }
+        }
+    });
+    let predicates = FLOAT_PREDICATE.iter().map(|method| {
+        let method = format_ident!("{method}");
+        quote! {
+            fn #method(self) -> bool {
+                #num::Float::#method(self.#field)
+            }
+        }
+    });
+    quote! {
+        impl<_A> #num::Float for #target<_A>
+        where
+            _A: #num::Float,
+            Self: #num::Num
+                + #num::NumCast
+                + ::core::ops::Neg<Output = Self>
+                + PartialOrd
+                + Copy,
+        {
+            #(#consts)*
+
+            #(#unary)*
+
+            #(#binary)*
+
+            #(#predicates)*
+
+            fn classify(self) -> ::core::num::FpCategory {
+                #num::Float::classify(self.#field)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                #target { #field: #num::Float::mul_add(self.#field, a.#field, b.#field) }
+            }
+
+            fn powi(self, n: i32) -> Self {
+                #target { #field: #num::Float::powi(self.#field, n) }
+            }
+
+            fn clamp(self, min: Self, max: Self) -> Self {
+                #target { #field: #num::Float::clamp(self.#field, min.#field, max.#field) }
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = #num::Float::sin_cos(self.#field);
+                (#target { #field: sin }, #target { #field: cos })
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                #num::Float::integer_decode(self.#field)
+            }
+        }
+    }
+}
+
+/// splits a trait of the given family, e.g. `CheckedAdd`, into its method, e.g.
+/// `checked_add`, and the operator of `core::ops` it requires, e.g. `Add`
+fn split_family(name: &Ident, family: &str) -> (Ident, Ident) {
+    let name = name.to_string();
+    let op = &name[family.len()..];
+    (
+        format_ident!("{}_{}", family.to_lowercase(), op.to_lowercase()),
+        format_ident!("{op}"),
+    )
+}
+
+const UNSUPPORTED: &str = "expected one of `Zero`, `One`, `Num`, `Bounded`, `Signed`, `Unsigned`, `Float`, \
+    `FromPrimitive`, `ToPrimitive`, `NumCast`, `Pow`, `CheckedAdd`, `CheckedSub`, `CheckedMul`, \
+    `CheckedDiv`, `CheckedRem`, `CheckedNeg`, `SaturatingAdd`, `SaturatingSub`, `SaturatingMul`, \
+    `WrappingAdd`, `WrappingSub`, `WrappingMul` or `WrappingNeg`";
@@ -0,0 +1,165 @@
+/*
+    appellation: impl_ops <module>
+    authors: @FL03
+*/
+use super::{binary::impl_binary_ops, unary::impl_wrapper_unary_ops};
+use crate::ast::{MethodCallAst, OpsGroup, OpsSet, ParamBounds, WrapperImpls};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Data, DataStruct, DeriveInput, Fields, GenericParam, Generics, Token, parse_quote};
+
+/// Procedural macro entry point
+pub fn impl_wrapper_ops(input: WrapperImpls<OpsGroup>) -> TokenStream {
+    let set = input.ops.iter().copied().collect::<OpsSet>();
+    impl_ops_set(&input, set)
+}
+
+/// Derive macro entry point
+pub fn impl_derive_wrapper_ops(input: &DeriveInput) -> TokenStream {
+    match _impl_derive_wrapper_ops(input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn _impl_derive_wrapper_ops(
+    DeriveInput {
+        attrs,
+        data,
+        generics,
+        ident,
+        ..
+    }: &DeriveInput,
+) -> syn::Result<TokenStream> {
+    // ensure the input is a single field struct generic over the type of its field
+    let fields = match data {
+        Data::Struct(DataStruct { fields, .. }) if fields.len() == 1 => fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`WrapperOps` can only be derived for single field structs",
+            ));
+        }
+    };
+    let bounds = param_bounds(generics)?;
+    let field = match fields {
+        Fields::Named(_) => fields.iter().next().and_then(|f| f.ident.clone()),
+        _ => None,
+    };
+    // collect the groups from each of the `ops` attributes, defaulting to `all`
+    let mut set = OpsSet::default();
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ops")) {
+        let groups = attr.parse_args_with(Punctuated::<OpsGroup, Token![,]>::parse_terminated)?;
+        groups.into_iter().for_each(|group| set.insert(group));
+        found = true;
+    }
+    if !found {
+        set = OpsSet::all();
+    }
+    let input = WrapperImpls::<OpsGroup> {
+        impl_token: Default::default(),
+        generics: None,
+        target: ident.clone(),
+        args: None,
+        field,
+        ctor: None,
+        where_clause: None,
+        ops: Punctuated::new(),
+        bounds: Some(bounds),
+    };
+    Ok(impl_ops_set(&input, set))
+}
+
+/// resolves the single type parameter of the struct along with its bounds, rejecting any
+/// lifetime or const parameters
+fn param_bounds(generics: &Generics) -> syn::Result<ParamBounds> {
+    let mut params = generics.params.iter();
+    let param = match (params.next(), params.next()) {
+        (Some(GenericParam::Type(param)), None) => param,
+        (Some(GenericParam::Type(_)), Some(param)) | (Some(param), _) => {
+            return Err(syn::Error::new_spanned(
+                param,
+                "`WrapperOps` only supports a single type parameter, without lifetimes or \
+                 const parameters",
+            ));
+        }
+        (None, _) => {
+            return Err(syn::Error::new_spanned(
+                generics,
+                "`WrapperOps` requires the struct to be generic over the type of its field",
+            ));
+        }
+    };
+    let ident = &param.ident;
+    let mut predicates = Vec::new();
+    if !param.bounds.is_empty() {
+        let bounds = &param.bounds;
+        predicates.push(parse_quote!(#ident: #bounds));
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        predicates.extend(where_clause.predicates.iter().cloned());
+    }
+    Ok(ParamBounds {
+        param: ident.clone(),
+        predicates,
+    })
+}
+
+/// implements each operation within the set onto the target
+fn impl_ops_set(input: &WrapperImpls<OpsGroup>, set: OpsSet) -> TokenStream {
+    let mut binary: Punctuated<MethodCallAst, Token![,]> = Punctuated::new();
+    if set.arith {
+        binary.extend::<[MethodCallAst; 5]>([
+            parse_quote!(Add.add),
+            parse_quote!(Sub.sub),
+            parse_quote!(Mul.mul),
+            parse_quote!(Div.div),
+            parse_quote!(Rem.rem),
+        ]);
+    }
+    if set.bits {
+        binary.extend::<[MethodCallAst; 3]>([
+            parse_quote!(BitAnd.bitand),
+            parse_quote!(BitOr.bitor),
+            parse_quote!(BitXor.bitxor),
+        ]);
+    }
+    if set.shifts {
+        binary.extend::<[MethodCallAst; 2]>([parse_quote!(Shl.shl), parse_quote!(Shr.shr)]);
+    }
+    let mut unary: Punctuated<MethodCallAst, Token![,]> = Punctuated::new();
+    if set.neg {
+        unary.push(parse_quote!(Neg.neg));
+    }
+    if set.not {
+        unary.push(parse_quote!(Not.not));
+    }
+    let binary = impl_binary_ops(&wrapper_impls(input, binary), set.assign);
+    let unary = impl_wrapper_unary_ops(wrapper_impls(input, unary));
+    quote! {
+        #binary
+
+        #unary
+    }
+}
+
+/// returns the input of the binary and unary macros implementing the given operations onto the
+/// target of the given input
+fn wrapper_impls(
+    input: &WrapperImpls<OpsGroup>,
+    ops: Punctuated<MethodCallAst, Token![,]>,
+) -> WrapperImpls {
+    WrapperImpls {
+        impl_token: input.impl_token,
+        generics: input.generics.clone(),
+        target: input.target.clone(),
+        args: input.args.clone(),
+        field: input.field.clone(),
+        ctor: input.ctor.clone(),
+        where_clause: input.where_clause.clone(),
+        ops,
+        bounds: input.bounds.clone(),
+    }
+}
@@ -2,13 +2,21 @@
     appellation: impl_unary <module>
     authors: @FL03
 */
-use crate::ast::{MethodCallAst, WrapperImpls};
+use crate::ast::WrapperImpls;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Ident;
+use syn::Member;
 
 /// Procedural macro entry point
 pub fn impl_wrapper_unary_ops(input: WrapperImpls) -> TokenStream {
+    // the operations do not accept any arguments, output types nor assign operations
+    if let Err(err) = input.ops.iter().try_for_each(|op| {
+        op.deny_args()?;
+        op.deny_output()?;
+        op.deny_assign()
+    }) {
+        return err.to_compile_error();
+    }
     let base = impl_core_unary_ops(&input);
 
     quote! {
@@ -17,96 +25,57 @@ pub fn impl_wrapper_unary_ops(input: WrapperImpls) -> TokenStream {
     }
 }
 
-fn impl_core_unary_ops(
-    WrapperImpls {
-        target, field, ops, ..
-    }: &WrapperImpls,
-) -> Vec<TokenStream> {
+fn impl_core_unary_ops(input: &WrapperImpls) -> Vec<TokenStream> {
+    let field = match &input.field {
+        Some(f) => Member::Named(f.clone()),
+        None => Member::from(0),
+    };
+    let params = input.params();
+    let (source, target) = (input.target_ty(&quote!(_A)), input.target_ty(&quote!(_B)));
+    let bounds = [input.bounds(&quote!(_A)), input.bounds(&quote!(_B))].concat();
     let mut impls = Vec::new();
-    for MethodCallAst { name: op, call, .. } in ops {
-        let _impl = if let Some(f) = field {
-            impl_named(op, target, call, f)
-        } else {
-            impl_tuple(op, target, call)
-        };
-        impls.push(_impl);
-    }
-    impls
-}
-
-fn impl_tuple(op: &Ident, target: &Ident, call: &Ident) -> TokenStream {
-    quote! {
-        impl<_A, _B> ::core::ops::#op for #target<_A>
-        where
-            _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
-
-            fn #call(self) -> Self::Output {
-                #target(::core::ops::#op::#call(self.0))
-            }
-        }
-
-        impl<'a, _A, _B> ::core::ops::#op for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
-
-            fn #call(self) -> Self::Output {
-                #target(::core::ops::#op::#call(&self.0))
+    for item in &input.ops {
+        let (op, call) = (&item.trait_path(), &item.call);
+        let owned = input.construct(&field, quote!(#op::#call(self.#field)));
+        let shared = input.construct(&field, quote!(#op::#call(&self.#field)));
+        let exclusive = input.construct(&field, quote!(#op::#call(&mut self.#field)));
+        impls.push(quote! {
+            impl<_A, _B #(, #params)*> #op for #source
+            where
+                _A: #op<Output = _B>,
+                #(#bounds,)*
+            {
+                type Output = #target;
+
+                fn #call(self) -> Self::Output {
+                    #owned
+                }
             }
-        }
 
-        impl<'a, _A, _B> ::core::ops::#op for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
+            impl<'a, _A, _B #(, #params)*> #op for &'a #source
+            where
+                &'a _A: #op<Output = _B>,
+                #(#bounds,)*
+            {
+                type Output = #target;
 
-            fn #call(self) -> Self::Output {
-                #target(::core::ops::#op::#call(&mut self.0))
+                fn #call(self) -> Self::Output {
+                    #shared
+                }
             }
-        }
-    }
-}
-
-fn impl_named(op: &Ident, target: &Ident, call: &Ident, field: &Ident) -> TokenStream {
-    quote! {
-        impl<_A, _B> ::core::ops::#op for #target<_A>
-        where
-            _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
-
-            fn #call(self) -> Self::Output {
-                let #field = ::core::ops::#op::#call(self.#field);
-                #target { #field }
-            }
-        }
 
-        impl<'a, _A, _B> ::core::ops::#op for &'a #target<_A>
-        where
-            &'a _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
+            impl<'a, _A, _B #(, #params)*> #op for &'a mut #source
+            where
+                &'a mut _A: #op<Output = _B>,
+                #(#bounds,)*
+            {
+                type Output = #target;
 
-            fn #call(self) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&self.#field);
-                #target { #field }
+                fn #call(self) -> Self::Output {
+                    #exclusive
+                }
             }
-        }
-
-        impl<'a, _A, _B> ::core::ops::#op for &'a mut #target<_A>
-        where
-            &'a mut _A: ::core::ops::#op<Output = _B>,
-        {
-            type Output = #target<_B>;
-
-            fn #call(self) -> Self::Output {
-                let #field = ::core::ops::#op::#call(&mut self.#field);
-                #target { #field }
-            }
-        }
+        });
     }
+    impls
 }
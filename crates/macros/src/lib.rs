@@ -6,24 +6,42 @@
 extern crate proc_macro;
 
 mod ast {
-    pub use self::wrapper_impl_ast::*;
+    pub use self::{collection_ast::*, ops_ast::*, wrapper_impl_ast::*};
 
+    mod collection_ast;
+    mod ops_ast;
     mod wrapper_impl_ast;
 }
 
 mod impls {
-    pub use self::{binary::impl_wrapper_binary_ops, unary::impl_wrapper_unary_ops};
+    pub use self::{
+        binary::impl_wrapper_binary_ops,
+        cmp::impl_wrapper_cmp_ops,
+        collection::impl_wrapper_collection,
+        int::impl_wrapper_int_ops,
+        num::impl_wrapper_num_ops,
+        ops::{impl_derive_wrapper_ops, impl_wrapper_ops},
+        unary::impl_wrapper_unary_ops,
+    };
 
     pub mod binary;
+    pub mod cmp;
+    pub mod collection;
+    pub mod int;
+    pub mod num;
+    pub mod ops;
     pub mod unary;
 }
 
-use crate::ast::WrapperImpls;
+use crate::ast::{CollectionGroup, OpsGroup, WrapperImpls};
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use syn::{DeriveInput, Ident, parse_macro_input};
 
 /// The [`binary_wrapper!`] macro generates implementations for the core binary operations
 /// onto a generic wrapper type. It supports both tuple structs and structs with named fields.
+/// Requesting the `Add` or `Mul` operations additionally implements the
+/// [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product) traits, respectively, for both
+/// owned and borrowed items.
 ///
 /// ```rust
 /// extern crate contained_macros as macros;
@@ -60,6 +78,164 @@ use syn::parse_macro_input;
 ///     }
 /// }
 /// ```
+///
+/// Each operator defined within `core::ops` is paired with its assign counterpart, e.g.
+/// `AddAssign.add_assign` for `Add.add`. Custom operators sharing the same shape may be given
+/// using their full path, in which case the assign operation is only implemented when given
+/// explicitly:
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// pub mod algebra {
+///     pub trait Dot<Rhs = Self> {
+///         type Output;
+///
+///         fn dot(self, rhs: Rhs) -> Self::Output;
+///     }
+///
+///     pub trait DotAssign<Rhs = Self> {
+///         fn dot_assign(&mut self, rhs: Rhs);
+///     }
+/// }
+///
+/// pub struct Wrapper<T>(pub T);
+///
+/// macros::binary_wrapper! {
+///     impl Wrapper {
+///         Add.add,
+///         algebra::Dot.dot => algebra::DotAssign.dot_assign,
+///     }
+/// }
+/// ```
+///
+/// By default, each operation is implemented between two instances of the target, producing
+/// another. Otherwise, the right-hand wrapper may be given within parentheses and the output
+/// wrapper following an arrow, e.g. `Mul.mul(Meters) -> SquareMeters`; the field of either
+/// defaults to that of the target and may be given explicitly, e.g. `Seconds.secs` or
+/// `Speed.0`. The assign operation is only implemented when the output is the target itself.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Meters<T>(pub T);
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct SquareMeters<T>(pub T);
+///
+/// pub struct Seconds<T> {
+///     pub secs: T,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Speed<T>(pub T);
+///
+/// macros::binary_wrapper! {
+///     impl Meters {
+///         Mul.mul(Meters) -> SquareMeters,
+///         Div.div(Seconds.secs) -> Speed,
+///     }
+/// }
+///
+/// assert_eq!(Meters(3) * Meters(4), SquareMeters(12));
+/// assert_eq!(Meters(10) / Seconds { secs: 2 }, Speed(5));
+/// ```
+///
+/// Prefixing the right-hand side with the `scalar` keyword implements the operation between
+/// the target and each of the listed types, in either order, applying the scalar directly to
+/// the inner value; e.g. `Mul.mul(scalar f64)` allows a wrapper over `Complex<f64>` to be
+/// scaled by a real `f64`. The assign operation is implemented for the scalar types as well.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Meters<T>(pub T);
+///
+/// macros::binary_wrapper! {
+///     impl Meters {
+///         Mul.mul(scalar i32, f64),
+///         Div.div(scalar f64),
+///     }
+/// }
+///
+/// assert_eq!(Meters(3) * 2, Meters(6));
+/// assert_eq!(2.0 * Meters(1.5), Meters(3.0));
+/// assert_eq!(Meters(9.0) / 3.0, Meters(3.0));
+/// ```
+///
+/// Using `rhs scalar` instead restricts the scalar to the right-hand side of the operation,
+/// e.g. `Div.div(rhs scalar f64)` implements `Meters<T> / f64` without `f64 / Meters<T>`.
+///
+/// Targets declaring additional type parameters list them after the name, marking the wrapped
+/// type with an underscore, e.g. `impl Quantity<_, U>.value`; the parameters are carried over
+/// to every implementation and the target is constructed using its `new` function, allowing
+/// it to hold other fields such as a `PhantomData` marker. The same syntax is accepted by the
+/// [`unary_wrapper!`] macro.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// use core::marker::PhantomData;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Tagged<T, U> {
+///     value: T,
+///     tag: PhantomData<U>,
+/// }
+///
+/// impl<T, U> Tagged<T, U> {
+///     pub const fn new(value: T) -> Self {
+///         Self {
+///             value,
+///             tag: PhantomData,
+///         }
+///     }
+/// }
+///
+/// macros::binary_wrapper! {
+///     impl Tagged<_, U>.value {
+///         Add.add,
+///         Div.div(rhs scalar f64),
+///     }
+/// }
+///
+/// let (a, b) = (Tagged::<f64, ()>::new(1.0), Tagged::new(2.0));
+/// assert_eq!(a + b, Tagged::new(3.0));
+/// assert_eq!(Tagged::<f64, ()>::new(3.0) / 2.0, Tagged::new(1.5));
+/// ```
+///
+/// Targets upholding an invariant may name a checked constructor using the `with` keyword,
+/// followed by a where clause bounding the wrapped type, marked by an underscore; every result
+/// is then passed through the constructor, including those of the assign operations, which
+/// require the wrapped type to implement [`Default`] while the result is being checked.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// pub struct Positive<T>(T);
+///
+/// impl<T: PartialOrd + Default> Positive<T> {
+///     pub fn new(value: T) -> Self {
+///         assert!(value > T::default(), "the value must be positive");
+///         Self(value)
+///     }
+/// }
+///
+/// macros::binary_wrapper! {
+///     impl Positive with Positive::new where _: PartialOrd + Default {
+///         Add.add,
+///         Sub.sub,
+///     }
+/// }
+///
+/// let mut x = Positive::new(3) + Positive::new(2);
+/// x -= Positive::new(4);
+/// assert_eq!(x, Positive::new(1));
+/// assert!(std::panic::catch_unwind(|| Positive::new(1) - Positive::new(2)).is_err());
+/// ```
 #[proc_macro]
 pub fn binary_wrapper(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as WrapperImpls);
@@ -69,6 +245,8 @@ pub fn binary_wrapper(input: TokenStream) -> TokenStream {
 
 /// The [`unary_wrapper!`] macro generates implementations for the core unary operations
 /// onto a generic wrapper type. It supports both tuple structs and structs with named fields.
+/// As with the [`binary_wrapper!`] macro, custom operators may be given using their full path,
+/// e.g. `algebra::Norm.norm`.
 ///
 /// ```rust
 /// extern crate contained_macros as macros;
@@ -105,3 +283,327 @@ pub fn unary_wrapper(input: TokenStream) -> TokenStream {
     let output = impls::impl_wrapper_unary_ops(ast);
     output.into()
 }
+
+/// The [`cmp_wrapper!`] macro generates comparisons between a generic wrapper type and its
+/// inner value, i.e. `PartialEq<T> for Wrapper<T>` and `PartialOrd<T> for Wrapper<T>`. It
+/// supports both tuple structs and structs with named fields.
+///
+/// Each operation accepts an optional list of concrete types for which the reverse comparison,
+/// e.g. `PartialEq<Wrapper<i32>> for i32`, is implemented as well. Including `Self` within the
+/// list compares wrappers of different inner types, i.e. `PartialEq<Wrapper<B>> for
+/// Wrapper<A>`, in place of the comparison with the inner value; in this case the wrapper
+/// should not derive the same trait since the implementations would overlap.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(PartialEq, PartialOrd)]
+/// pub struct Wrapper<T>(pub T);
+///
+/// macros::cmp_wrapper! {
+///     impl Wrapper {
+///         PartialEq.eq(i32, f64),
+///         PartialOrd.partial_cmp(i32, f64),
+///     }
+/// }
+///
+/// assert!(Wrapper(5) == 5);
+/// assert!(5 == Wrapper(5));
+/// assert!(Wrapper(1.0) < 2.0);
+/// ```
+///
+/// or, for transparent structs with a named field, comparing across inner types:
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// pub struct Wrapper<T> {
+///     pub field: T,
+/// }
+///
+/// macros::cmp_wrapper! {
+///     impl Wrapper.field {
+///         PartialEq.eq(Self),
+///         PartialOrd.partial_cmp(Self),
+///     }
+/// }
+///
+/// let (a, b) = (Wrapper { field: "a" }, Wrapper { field: String::from("b") });
+/// assert!(a != b);
+/// ```
+#[proc_macro]
+pub fn cmp_wrapper(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as WrapperImpls);
+    let output = impls::impl_wrapper_cmp_ops(ast);
+    output.into()
+}
+
+/// The [`collection_wrapper!`] macro forwards the collection traits of the inner value onto a
+/// generic wrapper type, e.g. a newtype over a `Vec<T>`, `HashMap<K, V>` or `String`. It
+/// supports both tuple structs and structs with named fields. Each implementation is only
+/// available whenever the inner value supports it; the available groups are:
+///
+/// - `iter`: `IntoIterator` for owned, borrowed and mutably borrowed wrappers, along with the
+///   `iter` and `iter_mut` methods
+/// - `len`: the `len` and `is_empty` methods, available whenever the borrowed iterator of the
+///   inner value is an `ExactSizeIterator`
+/// - `text`: the `len`, `is_empty` and `chars` methods, available whenever the inner value
+///   implements `AsRef<str>`
+/// - `collect`: `FromIterator` and `Extend` for any item accepted by the inner value
+/// - `index`: `Index<I>` and `IndexMut<I>` for any index `I` accepted by the inner value
+/// - `all`: each of the groups listed above, except for `text`
+///
+/// Both `iter` and `len` rely on the borrowed inner value implementing `IntoIterator`, leaving
+/// them unavailable for wrappers over a `String` or `str`, whose characters are only iterated
+/// through explicit methods; such wrappers use the `text` group instead, which cannot be
+/// combined with `len` or `all` since they define the same methods.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// pub struct Label<T>(pub T);
+///
+/// macros::collection_wrapper! {
+///     impl Label {
+///         text,
+///         collect,
+///         index,
+///     }
+/// }
+///
+/// let label: Label<String> = ['a', 'b', 'c'].into_iter().collect();
+/// assert_eq!(label.len(), 3);
+/// assert_eq!(&label[1..], "bc");
+/// assert_eq!(label.chars().rev().collect::<String>(), "cba");
+/// ```
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// pub struct Stack<T>(pub T);
+///
+/// macros::collection_wrapper! {
+///     impl Stack {
+///         all,
+///     }
+/// }
+///
+/// let mut stack: Stack<Vec<i32>> = (1..=3).collect();
+/// stack.extend([4, 5]);
+/// stack[0] = 10;
+/// assert_eq!(stack.len(), 5);
+/// assert_eq!(&stack[1..3], &[2, 3]);
+/// assert_eq!(stack.iter().sum::<i32>(), 24);
+/// ```
+///
+/// or, for transparent structs with a named field:
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// use std::collections::HashMap;
+///
+/// pub struct Registry<T> {
+///     pub entries: T,
+/// }
+///
+/// macros::collection_wrapper! {
+///     impl Registry.entries {
+///         iter,
+///         collect,
+///         index,
+///     }
+/// }
+///
+/// let registry: Registry<HashMap<&str, u8>> = [("a", 1), ("b", 2)].into_iter().collect();
+/// assert_eq!(registry["b"], 2);
+/// assert_eq!(registry.into_iter().count(), 2);
+/// ```
+#[proc_macro]
+pub fn collection_wrapper(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as WrapperImpls<CollectionGroup>);
+    let output = impls::impl_wrapper_collection(ast);
+    output.into()
+}
+
+/// The [`int_wrapper!`] macro forwards the inherent method families of the primitive integers
+/// onto a generic wrapper type, implementing them as `const` methods for each of the integer
+/// types listed within the generics of the block. It supports both tuple structs and structs
+/// with named fields.
+///
+/// The shape of each method is determined by its prefix:
+///
+/// - `checked_*` methods return an `Option<W<T>>`
+/// - `overflowing_*` methods return a `(W<T>, bool)`
+/// - `saturating_*` and `wrapping_*` methods, as well as `pow`, return a `W<T>`
+///
+/// where the operation is one of `add`, `sub`, `mul`, `div`, `rem`, `div_euclid` and
+/// `rem_euclid`, each accepting another wrapper, `pow`, `shl` and `shr`, each accepting a
+/// `u32`, or `neg` and `abs`, which accept no arguments. Additionally, `abs_diff` returns the
+/// difference wrapped around the unsigned counterpart of the integer type.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Wrapper<T>(pub T);
+///
+/// macros::int_wrapper! {
+///     impl<u8, i32> Wrapper {
+///         checked_add,
+///         wrapping_mul,
+///         saturating_sub,
+///         overflowing_add,
+///         pow,
+///         abs_diff,
+///     }
+/// }
+///
+/// assert_eq!(Wrapper(250u8).checked_add(Wrapper(10)), None);
+/// assert_eq!(Wrapper(-3i32).abs_diff(Wrapper(4)), Wrapper(7u32));
+/// ```
+///
+/// or, for transparent structs with a named field:
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Wrapper<T> {
+///     pub field: T,
+/// }
+///
+/// macros::int_wrapper! {
+///     impl<u64> Wrapper.field {
+///         checked_div,
+///         overflowing_sub,
+///         wrapping_shl,
+///     }
+/// }
+///
+/// let (value, overflow) = Wrapper { field: 0u64 }.overflowing_sub(Wrapper { field: 1 });
+/// assert_eq!(value, Wrapper { field: u64::MAX });
+/// assert!(overflow);
+/// ```
+#[proc_macro]
+pub fn int_wrapper(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as WrapperImpls<Ident>);
+    let output = impls::impl_wrapper_int_ops(ast);
+    output.into()
+}
+
+/// The [`num_wrapper!`] macro implements the `num_traits` interfaces for a generic wrapper
+/// type by forwarding to the inner value, allowing numeric newtypes to be used within generic
+/// numerical code. It supports both tuple structs and structs with named fields. The generated
+/// code refers to the traits through the `num_traits` re-export of the `contained` crate,
+/// whose documentation hosts the runnable examples of the macro.
+///
+/// The supported traits are `Zero`, `One`, `Num`, `Bounded`, `Signed`, `Unsigned`, `Float`,
+/// `FromPrimitive`, `ToPrimitive`, `NumCast`, `Pow`, the `Checked*` family (`Add`, `Sub`,
+/// `Mul`, `Div`, `Rem` and `Neg`), the `Saturating*` family (`Add`, `Sub` and `Mul`) and the
+/// `Wrapping*` family (`Add`, `Sub`, `Mul` and `Neg`). Traits extending the arithmetic
+/// operators, such as `Num`, require the wrapper to implement those operators as well, e.g.
+/// using [`binary_wrapper!`]. The `Float` trait is only available alongside the `std` feature
+/// of `contained`.
+///
+/// ```text
+/// num_wrapper! {
+///     impl Wrapper.field {
+///         FromPrimitive,
+///         ToPrimitive,
+///         CheckedAdd,
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn num_wrapper(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as WrapperImpls<Ident>);
+    let output = impls::impl_wrapper_num_ops(ast);
+    output.into()
+}
+
+/// The [`ops_wrapper!`] macro implements groups of operations onto a generic wrapper type,
+/// expanding to the same implementations as the [`binary_wrapper!`] and [`unary_wrapper!`]
+/// macros. It supports both tuple structs and structs with named fields. The available groups
+/// are:
+///
+/// - `arith`: `Add`, `Sub`, `Mul`, `Div` and `Rem`, along with `Sum` and `Product`
+/// - `bits`: `BitAnd`, `BitOr` and `BitXor`
+/// - `shifts`: `Shl` and `Shr`
+/// - `neg`: `Neg`
+/// - `not`: `Not`
+/// - `assign`: the assign counterparts of the selected binary operations
+/// - `all`: each of the groups listed above
+///
+/// As with the [`binary_wrapper!`] macro, targets declaring additional type parameters list
+/// them after the name, e.g. `impl Quantity<_, U>.value`.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Wrapper<T>(pub T);
+///
+/// macros::ops_wrapper! {
+///     impl Wrapper {
+///         arith,
+///         neg,
+///         assign,
+///     }
+/// }
+///
+/// let mut x = -Wrapper(2) * Wrapper(3);
+/// x += Wrapper(1);
+/// assert_eq!(x, Wrapper(-5));
+/// ```
+///
+/// or, for transparent structs with a named field:
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// pub struct Wrapper<T> {
+///     pub field: T,
+/// }
+///
+/// macros::ops_wrapper! {
+///     impl Wrapper.field {
+///         all,
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn ops_wrapper(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as WrapperImpls<OpsGroup>);
+    let output = impls::impl_wrapper_ops(ast);
+    output.into()
+}
+
+/// The [`WrapperOps`] derive implements groups of operations onto a generic, single field
+/// struct, reading the target and its field from the definition itself. The groups are
+/// selected using the `#[ops(...)]` attribute, accepting the same groups as the
+/// [`ops_wrapper!`] macro, and default to `all` when the attribute is omitted. The bounds of
+/// the type parameter, including those of the where clause, carry over to every type wrapped
+/// within the generated implementations; lifetimes and const parameters are not supported.
+///
+/// ```rust
+/// extern crate contained_macros as macros;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, macros::WrapperOps)]
+/// #[ops(arith, bits, assign)]
+/// pub struct Wrapper<T> {
+///     pub value: T,
+/// }
+///
+/// let mut x = Wrapper { value: 6 } & Wrapper { value: 3 };
+/// x *= Wrapper { value: 4 };
+/// assert_eq!(x, Wrapper { value: 8 });
+/// ```
+#[proc_macro_derive(WrapperOps, attributes(ops))]
+pub fn wrapper_ops(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let output = impls::impl_derive_wrapper_ops(&ast);
+    output.into()
+}
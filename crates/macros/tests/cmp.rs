@@ -0,0 +1,57 @@
+/*
+    appellation: cmp <test>
+    authors: @FL03
+*/
+use contained_macros::cmp_wrapper;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct A<T>(pub T);
+
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct B<T> {
+    pub value: T,
+}
+
+cmp_wrapper! {
+    impl A {
+        PartialEq.eq(u8, f64),
+        PartialOrd.partial_cmp(u8, f64),
+    }
+}
+
+cmp_wrapper! {
+    impl B.value {
+        PartialEq.eq(Self, i32),
+        PartialOrd.partial_cmp(Self, i32),
+    }
+}
+
+#[test]
+fn test_cmp_inner() {
+    let a = A(5_u8);
+    assert!(a == 5);
+    assert!(a < 6);
+    assert!(a >= 5);
+    assert!(A(1.5) > 1.0);
+    // derived comparisons remain available
+    assert!(a == A(5));
+    assert!(a < A(10));
+}
+
+#[test]
+fn test_cmp_reverse() {
+    assert!(5 == A(5_u8));
+    assert!(4 < A(5_u8));
+    assert!(0.5 <= A(0.5));
+    assert!(1 == B { value: 1 });
+    assert!(2 > B { value: 1 });
+}
+
+#[test]
+fn test_cmp_cross_wrapper() {
+    let (a, b) = (B { value: "a" }, B { value: String::from("a") });
+    assert!(a == b);
+    assert!(B { value: 1 } < B { value: 2 });
+}
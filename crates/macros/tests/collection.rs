@@ -0,0 +1,112 @@
+/*
+    appellation: collection <test>
+    authors: @FL03
+*/
+use contained_macros::collection_wrapper;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stack<T>(pub T);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Registry<T> {
+    pub entries: T,
+}
+
+collection_wrapper! {
+    impl Stack {
+        all,
+    }
+}
+
+collection_wrapper! {
+    impl Registry.entries {
+        iter,
+        len,
+        collect,
+        index,
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Label<T> {
+    pub text: T,
+}
+
+collection_wrapper! {
+    impl Label.text {
+        text,
+        collect,
+        index,
+    }
+}
+
+#[test]
+fn test_into_iter() {
+    let mut stack = Stack(vec![1, 2, 3]);
+    for item in &mut stack {
+        *item *= 2;
+    }
+    assert_eq!((&stack).into_iter().copied().collect::<Vec<_>>(), [2, 4, 6]);
+    stack.iter_mut().for_each(|item| *item += 1);
+    assert_eq!(stack.iter().sum::<i32>(), 15);
+    assert_eq!(stack.into_iter().collect::<Vec<_>>(), [3, 5, 7]);
+
+    let registry = Registry {
+        entries: BTreeMap::from([("a", 1), ("b", 2)]),
+    };
+    assert_eq!(registry.iter().map(|(k, _)| *k).collect::<String>(), "ab");
+}
+
+#[test]
+fn test_collect_and_extend() {
+    let mut stack: Stack<Vec<u8>> = (0..4).collect();
+    stack.extend([4, 5]);
+    assert_eq!(stack, Stack(vec![0, 1, 2, 3, 4, 5]));
+    assert_eq!(stack.len(), 6);
+    assert!(!stack.is_empty());
+    assert!(Stack::<Vec<u8>>::default().is_empty());
+
+    let mut registry: Registry<HashMap<String, u32>> = [("x".to_string(), 1)].into_iter().collect();
+    registry.extend([("y".to_string(), 2)]);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn test_index() {
+    let mut stack = Stack(vec![10, 20, 30, 40]);
+    stack[1] += 5;
+    assert_eq!(stack[1], 25);
+    assert_eq!(&stack[1..3], &[25, 30]);
+    stack[2..].fill(0);
+    assert_eq!(stack.0, [10, 25, 0, 0]);
+
+    let registry = Registry {
+        entries: HashMap::from([("a", 1u8)]),
+    };
+    assert_eq!(registry["a"], 1);
+}
+
+#[test]
+fn test_string() {
+    // the borrowed string is not iterable, leaving `iter` and `len` unavailable
+    let mut text: Stack<String> = ['a', 'b'].into_iter().collect();
+    text.extend(["cd", "ef"]);
+    assert_eq!(text.0, "abcdef");
+    assert_eq!(&text[1..4], "bcd");
+    assert_eq!(text.0.len(), 6);
+    assert_eq!(text.0.chars().rev().collect::<Stack<String>>().0, "fedcba");
+}
+
+#[test]
+fn test_text() {
+    let mut label: Label<String> = ['a', 'b'].into_iter().collect();
+    label.extend(["cd", "é"]);
+    assert_eq!(label.len(), 6);
+    assert!(!label.is_empty());
+    assert_eq!(label.chars().count(), 5);
+    assert_eq!(&label[..2], "ab");
+
+    let label = Label { text: "" };
+    assert!(label.is_empty());
+}
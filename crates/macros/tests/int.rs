@@ -0,0 +1,72 @@
+/*
+    appellation: int <test>
+    authors: @FL03
+*/
+use contained_macros::int_wrapper;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct A<T>(pub T);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct B<T> {
+    pub value: T,
+}
+
+int_wrapper! {
+    impl<u8, i32> A {
+        checked_add,
+        checked_neg,
+        wrapping_mul,
+        saturating_sub,
+        overflowing_add,
+        pow,
+        abs_diff,
+    }
+}
+
+int_wrapper! {
+    impl<i64> B.value {
+        checked_div,
+        checked_rem_euclid,
+        overflowing_shl,
+        saturating_abs,
+        wrapping_neg,
+    }
+}
+
+#[test]
+fn test_int_wrapper() {
+    assert_eq!(A(250u8).checked_add(A(5)), Some(A(255)));
+    assert_eq!(A(250u8).checked_add(A(6)), None);
+    assert_eq!(A(1u8).checked_neg(), None);
+    assert_eq!(A(16u8).wrapping_mul(A(17)), A(16));
+    assert_eq!(A(3u8).saturating_sub(A(5)), A(0));
+    assert_eq!(A(255u8).overflowing_add(A(1)), (A(0), true));
+    assert_eq!(A(3i32).pow(4), A(81));
+    assert_eq!(A(-3i32).abs_diff(A(4)), A(7u32));
+    assert_eq!(A(i32::MIN).saturating_sub(A(1)), A(i32::MIN));
+}
+
+#[test]
+fn test_int_wrapper_named() {
+    let b = B { value: -7i64 };
+    assert_eq!(b.checked_div(B { value: 0 }), None);
+    assert_eq!(b.checked_rem_euclid(B { value: 3 }), Some(B { value: 2 }));
+    assert_eq!(
+        B { value: 1i64 }.overflowing_shl(65),
+        (B { value: 2 }, true)
+    );
+    assert_eq!(
+        B { value: i64::MIN }.saturating_abs(),
+        B { value: i64::MAX }
+    );
+    assert_eq!(b.wrapping_neg(), B { value: 7 });
+}
+
+#[test]
+fn test_int_wrapper_const() {
+    const VALUE: Option<A<u8>> = A(2u8).checked_add(A(3));
+    assert_eq!(VALUE, Some(A(5)));
+}
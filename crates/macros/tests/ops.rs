@@ -2,7 +2,7 @@
     appellation: macros <test>
     authors: @FL03
 */
-use contained_macros::{binary_wrapper, unary_wrapper};
+use contained_macros::{WrapperOps, binary_wrapper, ops_wrapper, unary_wrapper};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
@@ -58,6 +58,150 @@ unary_wrapper! {
     }
 }
 
+/// a set of custom operators sharing the shape of those defined within `core::ops`
+pub mod algebra {
+    pub trait Dot<Rhs = Self> {
+        type Output;
+
+        fn dot(self, rhs: Rhs) -> Self::Output;
+    }
+
+    pub trait DotAssign<Rhs = Self> {
+        fn dot_assign(&mut self, rhs: Rhs);
+    }
+
+    pub trait Norm {
+        type Output;
+
+        fn norm(self) -> Self::Output;
+    }
+
+    impl Dot for i32 {
+        type Output = i64;
+
+        fn dot(self, rhs: i32) -> i64 {
+            self as i64 * rhs as i64
+        }
+    }
+
+    impl DotAssign for i32 {
+        fn dot_assign(&mut self, rhs: i32) {
+            *self *= rhs;
+        }
+    }
+
+    impl Norm for i32 {
+        type Output = u32;
+
+        fn norm(self) -> u32 {
+            self.unsigned_abs()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct C<T> {
+    pub value: T,
+}
+
+binary_wrapper! {
+    impl C.value {
+        Add.add => AddAssign.add_assign,
+        algebra::Dot.dot => algebra::DotAssign.dot_assign,
+    }
+}
+
+unary_wrapper! {
+    impl C.value {
+        self::algebra::Norm.norm,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct D<T>(pub T);
+
+ops_wrapper! {
+    impl D {
+        arith,
+        shifts,
+        not,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tagged<T, U> {
+    pub value: T,
+    pub tag: core::marker::PhantomData<U>,
+}
+
+impl<T, U> Tagged<T, U> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            tag: core::marker::PhantomData,
+        }
+    }
+}
+
+ops_wrapper! {
+    impl Tagged<_, U>.value {
+        arith,
+        neg,
+        assign,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, WrapperOps)]
+#[ops(bits, neg)]
+#[ops(assign)]
+#[repr(transparent)]
+pub struct E<T> {
+    pub value: T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, WrapperOps)]
+#[repr(transparent)]
+pub struct F<T>(pub T);
+
+#[derive(Clone, Copy, Debug, PartialEq, WrapperOps)]
+#[ops(arith, neg)]
+pub struct G<T: Copy>(pub T)
+where
+    T: PartialOrd;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Meters<T>(pub T);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SquareMeters<T>(pub T);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Seconds<T> {
+    pub secs: T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Speed<T>(pub T);
+
+binary_wrapper! {
+    impl Meters {
+        Add.add,
+        Mul.mul(Meters) -> SquareMeters,
+        Div.div(Seconds.secs) -> Speed,
+        Mul.mul(scalar i32, f64),
+        Div.div(scalar f64),
+    }
+}
+
+binary_wrapper! {
+    impl Seconds.secs {
+        Sub.sub -> Seconds,
+        Mul.mul(Speed.0) -> Meters.0,
+    }
+}
+
 #[test]
 fn test_impl_binary_ops_on_tuple() {
     let (x, y) = (A(42), A(&2));
@@ -79,3 +223,106 @@ fn test_unary_impls() {
     assert_eq!(!a, A(false));
     assert_eq!(!b, B { value: false });
 }
+
+#[test]
+fn test_iter_folds() {
+    let items = [A(1.5_f64), A(2.0), A(4.0)];
+    assert_eq!(items.iter().sum::<A<f64>>(), A(7.5));
+    assert_eq!(items.into_iter().product::<A<f64>>(), A(12.0));
+
+    let items = [B { value: 1 }, B { value: 2 }, B { value: 3 }];
+    assert_eq!(items.iter().sum::<B<i32>>(), B { value: 6 });
+    assert_eq!(items.into_iter().product::<B<i32>>(), B { value: 6 });
+}
+
+#[test]
+fn test_custom_ops() {
+    use algebra::{Dot, DotAssign, Norm};
+
+    let (x, y) = (C { value: 3 }, C { value: -4 });
+    assert_eq!(x + y, C { value: -1 });
+    assert_eq!(x.dot(y), C { value: -12_i64 });
+    assert_eq!(y.norm(), C { value: 4_u32 });
+
+    let mut z = x;
+    z += y;
+    z.dot_assign(C { value: 2 });
+    assert_eq!(z, C { value: -2 });
+}
+
+#[test]
+fn test_ops_groups() {
+    let (x, y) = (D(12_u8), D(2_u8));
+    assert_eq!(x * y - D(4), D(20));
+    assert_eq!(x << y, D(48));
+    assert_eq!(!y, D(253));
+    assert_eq!([x, y].iter().sum::<D<u8>>(), D(14));
+
+    let mut e = E { value: 0b1100 } | E { value: 0b0011 };
+    e ^= E { value: 0b0101 };
+    assert_eq!(-e, E { value: -0b1010 });
+}
+
+#[test]
+fn test_ops_groups_with_params() {
+    let mut x = Tagged::<i32, ()>::new(6) - Tagged::new(2);
+    x *= Tagged::new(3);
+    assert_eq!(-x, Tagged::new(-12));
+    assert_eq!([x, x].iter().sum::<Tagged<i32, ()>>(), Tagged::new(24));
+}
+
+#[test]
+fn test_derive_ops_defaults_to_all() {
+    let mut x = F(5_i32);
+    x -= F(1);
+    x <<= F(1);
+    x %= F(5);
+    assert_eq!(x, F(3));
+    assert_eq!(-(x & F(1)), F(-1));
+    assert_eq!(!F(true), F(false));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_derive_ops_with_bounds() {
+    let (x, y) = (G(3_i32), G(4_i32));
+    assert_eq!(x + y, G(7));
+    assert_eq!(&x * &y, G(12));
+    assert_eq!(-x, G(-3));
+    assert_eq!([x, y].into_iter().sum::<G<i32>>(), G(7));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_heterogeneous_ops() {
+    let (x, y) = (Meters(3.0), Meters(4.0));
+    assert_eq!(x * y, SquareMeters(12.0));
+    assert_eq!(&x * &y, SquareMeters(12.0));
+    assert_eq!(x * &y, SquareMeters(12.0));
+    assert_eq!(&x * y, SquareMeters(12.0));
+
+    let t = Seconds { secs: 2.0 };
+    let v = Meters(10.0) / t;
+    assert_eq!(v, Speed(5.0));
+    assert_eq!(t * v, Meters(10.0));
+
+    let mut t = Seconds { secs: 5.0 } - t;
+    t -= Seconds { secs: 1.0 };
+    assert_eq!(t, Seconds { secs: 2.0 });
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_scalar_ops() {
+    let x = Meters(3.0);
+    assert_eq!(x * 2.0, Meters(6.0));
+    assert_eq!(2.0 * x, Meters(6.0));
+    assert_eq!(&x * 2.0, Meters(6.0));
+    assert_eq!(12.0 / x, Meters(4.0));
+    assert_eq!(Meters(8) * 2, Meters(16));
+
+    let mut y = x;
+    y *= 4.0;
+    y /= 2.0;
+    assert_eq!(y, Meters(6.0));
+}